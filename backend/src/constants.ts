@@ -11,8 +11,13 @@ export const FOREX_DIRECTIONS = ["USDC_TO_EURC", "EURC_TO_USDC"] as const;
 export const TRANSACTION_CONFIG = {
   FEE: "60000",
   TIMEOUT: 120,
+  // Ledgers a smart-account authorization stays valid (~10 minutes)
+  AUTH_VALIDITY_LEDGERS: 120,
 } as const;
 
+// Most requests the multisig returns per `list_requests` call
+export const MULTISIG_PAGE_SIZE = 50;
+
 export const NETWORK_PASSPHRASES: Record<NetworkType, string> = {
   MAINNET: Networks.PUBLIC,
   TESTNET: Networks.TESTNET,
@@ -1,9 +1,14 @@
 import {
   Address,
   Contract,
+  Operation,
+  SorobanRpc,
+  Transaction,
   TransactionBuilder,
+  hash,
   nativeToScVal,
   scValToNative,
+  xdr,
 } from "@stellar/stellar-sdk";
 import bigInt from "big-integer";
 import { appConfig } from "../config.js";
@@ -16,8 +21,9 @@ import {
   TransferDestination,
   MultisigState,
   MultisigRequestRecord,
+  MultisigRequestStatus,
 } from "../types.js";
-import { TRANSACTION_CONFIG } from "../constants.js";
+import { MULTISIG_PAGE_SIZE, TRANSACTION_CONFIG } from "../constants.js";
 import { TransactionError, BalanceFetchError, ValidationError } from "../errors.js";
 import { toI128, fromI128 } from "../utils/currency.js";
 import { stellarClient } from "./stellar.js";
@@ -52,22 +58,30 @@ function normalizeSnapshotEntry(entry: unknown): Record<string, unknown> | null
   if (Array.isArray(entry)) {
     const [
       id,
+      token,
       to,
       amount,
       approvals,
-      executed,
+      cancellations,
+      rejections,
+      status,
       initiator,
       createdAt,
+      expiresAt,
       completedAt,
     ] = entry;
     return {
       id,
+      token,
       to,
       amount,
       approvals,
-      executed,
+      cancellations,
+      rejections,
+      status,
       initiator,
       created_at: createdAt,
+      expires_at: expiresAt,
       completed_at: completedAt,
     };
   }
@@ -94,6 +108,23 @@ function toStringValue(value: unknown): string | null {
   return null;
 }
 
+// `RequestStatus` arrives as a single-element vec holding the variant name
+function parseRequestStatus(value: unknown): MultisigRequestStatus {
+  const raw = Array.isArray(value) ? value[0] : value;
+  switch (toStringValue(raw)) {
+    case "Executed":
+      return "executed";
+    case "Cancelled":
+      return "cancelled";
+    case "Expired":
+      return "expired";
+    case "Rejected":
+      return "rejected";
+    default:
+      return "pending";
+  }
+}
+
 function parseSnapshot(entry: unknown): MultisigRequestRecord | null {
   const snapshot = normalizeSnapshotEntry(entry);
   if (!snapshot) {
@@ -101,6 +132,7 @@ function parseSnapshot(entry: unknown): MultisigRequestRecord | null {
   }
 
   const toAddress = toStringValue(snapshot.to);
+  const tokenAddress = toStringValue(snapshot.token) ?? appConfig.usdcContractId;
   const initiatorAddress = toStringValue(snapshot.initiator);
   if (!toAddress || !initiatorAddress) {
     return null;
@@ -128,16 +160,20 @@ function parseSnapshot(entry: unknown): MultisigRequestRecord | null {
   const createdAt = Number(snapshot.created_at ?? snapshot.createdAt ?? 0);
   const completedValue = Number(snapshot.completed_at ?? snapshot.completedAt ?? 0);
   const id = Number(snapshot.id ?? 0);
-  const executed = Boolean(snapshot.executed ?? snapshot.is_executed ?? false);
+  const status = parseRequestStatus(snapshot.status);
+  const executed = status === "executed";
 
   return {
     id,
+    token: tokenAddress,
     to: toLabel,
     amount,
     approvals: approvalsDistinct,
     executed,
+    status,
     initiator: initiatorLabel,
     createdAt,
+    expiresAt: Number(snapshot.expires_at ?? snapshot.expiresAt ?? 0),
     completedAt: completedValue > 0 ? completedValue : undefined,
   };
 }
@@ -155,10 +191,88 @@ async function buildBaseTransaction(): Promise<TransactionBuilder> {
   }).setTimeout(TRANSACTION_CONFIG.TIMEOUT);
 }
 
+// `Signatures` map for the smart account's `__check_auth`, naming the admin
+// as a `Signer::Delegated` signer. Delegated signers carry no signature
+// bytes; they authorize `__check_auth` themselves.
+function delegatedAdminSignatures(): xdr.ScVal {
+  const signer = xdr.ScVal.scvVec([
+    xdr.ScVal.scvSymbol("Delegated"),
+    Address.fromString(stellarClient.getAdminPublicKey()).toScVal(),
+  ]);
+  return xdr.ScVal.scvVec([
+    xdr.ScVal.scvMap([
+      new xdr.ScMapEntry({ key: signer, val: xdr.ScVal.scvBytes(Buffer.alloc(0)) }),
+    ]),
+  ]);
+}
+
+// Fill in the smart accounts' authorization entries: each account entry is
+// signed for the admin's delegated signer, and the admin (the transaction
+// source) authorizes the matching `__check_auth` call through the envelope
+// signature.
+async function authorizeSmartAccounts(tx: Transaction): Promise<Transaction> {
+  const simulation = await stellarClient.simulateTransaction(tx);
+  if (!SorobanRpc.Api.isSimulationSuccess(simulation)) {
+    throw new TransactionError(
+      `Simulation failed: ${"error" in simulation ? simulation.error : "Unknown error"}`
+    );
+  }
+  const entries = simulation.result?.auth ?? [];
+  const addressCredentials = xdr.SorobanCredentialsType.sorobanCredentialsAddress().value;
+  if (!entries.some((entry) => entry.credentials().switch().value === addressCredentials)) {
+    return tx;
+  }
+
+  const { sequence } = await stellarClient.getLatestLedger();
+  const validUntil = sequence + TRANSACTION_CONFIG.AUTH_VALIDITY_LEDGERS;
+  const networkId = hash(Buffer.from(stellarClient.networkPassphrase));
+  const auth: xdr.SorobanAuthorizationEntry[] = [];
+  for (const entry of entries) {
+    auth.push(entry);
+    if (entry.credentials().switch().value !== addressCredentials) {
+      continue;
+    }
+    const credentials = entry.credentials().address();
+    const account = Address.fromScAddress(credentials.address());
+    credentials.signatureExpirationLedger(validUntil);
+    credentials.signature(delegatedAdminSignatures());
+
+    const preimage = xdr.HashIdPreimage.envelopeTypeSorobanAuthorization(
+      new xdr.HashIdPreimageSorobanAuthorization({
+        networkId,
+        nonce: credentials.nonce(),
+        signatureExpirationLedger: validUntil,
+        invocation: entry.rootInvocation(),
+      })
+    );
+    auth.push(
+      new xdr.SorobanAuthorizationEntry({
+        credentials: xdr.SorobanCredentials.sorobanCredentialsSourceAccount(),
+        rootInvocation: new xdr.SorobanAuthorizedInvocation({
+          function: xdr.SorobanAuthorizedFunction.sorobanAuthorizedFunctionTypeContractFn(
+            new xdr.InvokeContractArgs({
+              contractAddress: account.toScAddress(),
+              functionName: "__check_auth",
+              args: [xdr.ScVal.scvBytes(hash(preimage.toXDR()))],
+            })
+          ),
+          subInvocations: [],
+        }),
+      })
+    );
+  }
+
+  const operation = tx.operations[0] as Operation.InvokeHostFunction;
+  return TransactionBuilder.cloneFrom(tx)
+    .clearOperations()
+    .addOperation(Operation.invokeHostFunction({ func: operation.func, auth }))
+    .build();
+}
+
 async function prepareAndSendTransaction(
   txBuilder: TransactionBuilder
 ): Promise<TransactionResult> {
-  let tx = txBuilder.build();
+  let tx = await authorizeSmartAccounts(txBuilder.build());
 
   tx = await stellarClient.prepareTransaction(tx);
   tx.sign(stellarClient.getAdminKeypair());
@@ -265,19 +379,30 @@ export async function fetchForexBalances(): Promise<ForexBalanceMap> {
   };
 }
 
+async function simulateMultisigCall(method: string, ...args: xdr.ScVal[]): Promise<unknown> {
+  const multisigContract = new Contract(appConfig.multisig.contractId);
+  const txBuilder = (await buildBaseTransaction()).addOperation(
+    multisigContract.call(method, ...args)
+  );
+  let tx = txBuilder.build();
+  tx = await stellarClient.prepareTransaction(tx);
+  const simulation = await stellarClient.simulateTransaction(tx);
+  if (!("result" in simulation) || !simulation.result?.retval) {
+    return null;
+  }
+  return scValToNative(simulation.result.retval);
+}
+
 async function fetchMultisigRequests(): Promise<MultisigRequestRecord[]> {
   try {
-    const multisigContract = new Contract(appConfig.multisig.contractId);
-    const txBuilder = (await buildBaseTransaction()).addOperation(
-      multisigContract.call("list_requests")
+    // Only the most recent page is shown
+    const count = Number((await simulateMultisigCall("request_count")) ?? 0);
+    const start = Math.max(0, count - MULTISIG_PAGE_SIZE);
+    const native = await simulateMultisigCall(
+      "list_requests",
+      nativeToScVal(start, { type: "u32" }),
+      nativeToScVal(MULTISIG_PAGE_SIZE, { type: "u32" })
     );
-    let tx = txBuilder.build();
-    tx = await stellarClient.prepareTransaction(tx);
-    const simulation = await stellarClient.simulateTransaction(tx);
-    if (!("result" in simulation) || !simulation.result?.retval) {
-      return [];
-    }
-    const native = scValToNative(simulation.result.retval);
     if (!Array.isArray(native)) {
       return [];
     }
@@ -414,6 +539,7 @@ export async function submitMultisigWithdraw(
       initiatorContract.call(
         "initiate_multisig_withdraw",
         nativeToScVal(multisigAddress, { type: "address" }),
+        nativeToScVal(Address.fromString(appConfig.usdcContractId), { type: "address" }),
         nativeToScVal(toAddress, { type: "address" }),
         nativeToScVal(amountI128.toString(), { type: "i128" })
       )
@@ -44,6 +44,16 @@ export class StellarClient {
     }
   }
 
+  async getLatestLedger() {
+    try {
+      return await this.rpc.getLatestLedger();
+    } catch (error) {
+      throw new NetworkError(
+        `Failed to fetch latest ledger: ${error instanceof Error ? error.message : "Unknown error"}`
+      );
+    }
+  }
+
   async simulateTransaction(tx: any) {
     try {
       return await this.rpc.simulateTransaction(tx);
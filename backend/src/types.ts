@@ -22,14 +22,24 @@ export type TransactionResult = {
 
 export type BalanceMap = Record<AccountLabel, string>;
 
+export type MultisigRequestStatus =
+  | "pending"
+  | "executed"
+  | "cancelled"
+  | "expired"
+  | "rejected";
+
 export type MultisigRequestRecord = {
   id: number;
+  token: string;
   to: AccountLabel;
   amount: string;
   approvals: AccountLabel[];
   executed: boolean;
+  status: MultisigRequestStatus;
   initiator: AccountLabel;
   createdAt: number;
+  expiresAt: number;
   completedAt?: number;
 };
 
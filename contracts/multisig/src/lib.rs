@@ -3,8 +3,8 @@
 use soroban_sdk::auth::{Context, CustomAccountInterface};
 use soroban_sdk::crypto::Hash;
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, panic_with_error, token, Address, Env,
-    Map, String, Val, Vec,
+    contract, contracterror, contractevent, contractimpl, contracttype, panic_with_error, token,
+    vec, Address, Env, IntoVal, Map, String, Symbol, Val, Vec,
 };
 use stellar_accounts::smart_account::{
     add_context_rule, add_policy, add_signer, do_check_auth, get_context_rule, get_context_rules,
@@ -17,24 +17,110 @@ use stellar_accounts::smart_account::{
 #[contracttype]
 pub enum DataKey {
     Admin,
-    Token,
+    Assets,
+    AssetPolicy(Address),
     Signers,
     Threshold,
     Label,
     NextRequestId,
     Request(u32),
+    NextProposalId,
+    Proposal(u32),
+    Vetoer,
+    Destinations,
+    QuorumRule,
+    QuorumPolicy,
+    OpenRequests,
+    OpenProposals,
+}
+
+/// How long a withdrawal request stays open for approval.
+pub const REQUEST_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// How long a governance proposal stays open for approval.
+pub const PROPOSAL_TTL_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Most withdrawal requests, and separately governance proposals, that may
+/// be open at once. Expired ones make room again; the bound keeps the work
+/// of dropping a removed signer's votes constant.
+pub const MAX_OPEN_REQUESTS: u32 = 20;
+
+/// Most entries `list_requests` and `list_proposals` return per call.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const PERSISTENT_TTL_EXTEND: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_TTL_THRESHOLD: u32 = PERSISTENT_TTL_EXTEND - DAY_IN_LEDGERS;
+
+/// `Expired` is never stored: a `Pending` request reads as expired once
+/// `expires_at` has passed.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RequestStatus {
+    Pending,
+    Executed,
+    Cancelled,
+    Expired,
+    Rejected,
+}
+
+/// Per-asset overrides. `threshold` replaces the treasury threshold for
+/// withdrawals of the asset and `max_amount` caps a single withdrawal;
+/// `None` keeps the default.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub struct AssetPolicy {
+    pub threshold: Option<u32>,
+    pub max_amount: Option<i128>,
 }
 
 #[derive(Clone)]
 #[contracttype]
 pub struct WithdrawalRequest {
     pub id: u32,
+    pub token: Address,
     pub to: Address,
     pub amount: i128,
     pub approvals: Vec<Address>,
+    /// Signers other than the initiator voting to cancel.
+    pub cancellations: Vec<Address>,
+    pub rejections: Vec<Address>,
+    pub status: RequestStatus,
+    pub initiator: Address,
+    pub created_at: u64,
+    pub expires_at: u64,
+    pub completed_at: u64,
+}
+
+/// Change to the treasury's own configuration, voted on by the current
+/// signers like a withdrawal.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[contracttype]
+pub enum GovernanceAction {
+    AddSigner(Address),
+    RemoveSigner(Address),
+    SetThreshold(u32),
+    /// Grant (or with `None`, revoke) the power to veto any withdrawal.
+    SetVetoer(Option<Address>),
+    /// Allow (or stop) withdrawals to an address, signer or not.
+    AddDestination(Address),
+    RemoveDestination(Address),
+    /// Allow (or stop) withdrawals of a token held by the treasury.
+    AddAsset(Address),
+    RemoveAsset(Address),
+    SetAssetPolicy(Address, AssetPolicy),
+}
+
+#[derive(Clone)]
+#[contracttype]
+pub struct GovernanceProposal {
+    pub id: u32,
+    pub action: GovernanceAction,
+    pub approvals: Vec<Address>,
     pub executed: bool,
     pub initiator: Address,
     pub created_at: u64,
+    pub expires_at: u64,
     pub completed_at: u64,
 }
 
@@ -44,21 +130,141 @@ pub struct MultisigSummary {
     pub threshold: u32,
     pub signer_count: u32,
     pub label: String,
+    /// Treasury balance of every allowed asset.
+    pub balances: Map<Address, i128>,
 }
 
 #[derive(Clone)]
 #[contracttype]
 pub struct WithdrawalSnapshot {
     pub id: u32,
+    pub token: String,
     pub to: String,
     pub amount: i128,
     pub approvals: Vec<String>,
-    pub executed: bool,
+    pub cancellations: Vec<String>,
+    pub rejections: Vec<String>,
+    pub status: RequestStatus,
     pub initiator: String,
     pub created_at: u64,
+    pub expires_at: u64,
     pub completed_at: u64,
 }
 
+/// Published when a signer opens a new withdrawal request. The initiator's
+/// approval is counted immediately.
+#[contractevent(topics = ["withdraw_proposed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalProposed {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub initiator: Address,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub threshold: u32,
+}
+
+/// Published for every approval after the proposal, with the running count.
+#[contractevent(topics = ["withdraw_approved"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalApproved {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub signer: Address,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+    pub approvals: u32,
+    pub threshold: u32,
+}
+
+/// Published once the threshold is met and the funds have been released.
+#[contractevent(topics = ["withdraw_executed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalExecuted {
+    #[topic]
+    pub id: u32,
+    pub token: Address,
+    pub to: Address,
+    pub amount: i128,
+}
+
+/// Published when a pending withdrawal is cancelled, either by its
+/// initiator or once a threshold of signers has voted to cancel.
+#[contractevent(topics = ["withdraw_cancelled"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalCancelled {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub signer: Address,
+    pub cancellations: u32,
+}
+
+/// Published for every rejection of a withdrawal. `closed` is set when the
+/// request can no longer pass, or when the vetoer rejected it.
+#[contractevent(topics = ["withdraw_rejected"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRejected {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub signer: Address,
+    pub rejections: u32,
+    pub closed: bool,
+}
+
+/// Published when a withdrawal is authorized natively through the
+/// treasury's context rules instead of the request flow.
+#[contractevent(topics = ["withdraw_authorized"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalAuthorized {
+    #[topic]
+    pub to: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when a signer opens a governance proposal. As with
+/// withdrawals, the initiator's approval is counted immediately.
+#[contractevent(topics = ["governance_proposed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceProposed {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub initiator: Address,
+    pub action: GovernanceAction,
+    pub threshold: u32,
+}
+
+/// Published for every approval after the proposal, with the running count.
+#[contractevent(topics = ["governance_approved"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceApproved {
+    #[topic]
+    pub id: u32,
+    #[topic]
+    pub signer: Address,
+    pub approvals: u32,
+    pub threshold: u32,
+}
+
+/// Published once a proposal reaches the threshold and the new signer set
+/// or threshold is in force.
+#[contractevent(topics = ["governance_executed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GovernanceExecuted {
+    #[topic]
+    pub id: u32,
+    pub action: GovernanceAction,
+    pub signer_count: u32,
+    pub threshold: u32,
+}
+
 #[contracterror]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum MultisigError {
@@ -69,6 +275,18 @@ pub enum MultisigError {
     RequestNotFound = 5,
     DuplicateApproval = 6,
     DestinationNotAllowed = 7,
+    // 8 is retired: setup runs once, in `__constructor`
+    DuplicateSigner = 9,
+    SignerNotFound = 10,
+    RequestExpired = 11,
+    RequestClosed = 12,
+    DuplicateDestination = 13,
+    AssetNotAllowed = 14,
+    DuplicateAsset = 15,
+    LimitExceeded = 16,
+    ApprovalRequired = 17,
+    QuorumRuleInstalled = 18,
+    TooManyOpen = 19,
 }
 
 #[contract]
@@ -82,11 +300,22 @@ fn read_admin(env: &Env) -> Address {
         .expect("admin not configured")
 }
 
-fn read_token(env: &Env) -> Address {
+fn read_assets(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Assets)
+        .expect("assets not configured")
+}
+
+fn read_asset_policy(env: &Env, token: &Address) -> Option<AssetPolicy> {
     env.storage()
         .instance()
-        .get(&DataKey::Token)
-        .expect("token not configured")
+        .get(&DataKey::AssetPolicy(token.clone()))
+}
+
+/// Id of the context rule added by `install_signer_rule`, if any.
+fn read_quorum_rule(env: &Env) -> Option<u32> {
+    env.storage().instance().get(&DataKey::QuorumRule)
 }
 
 fn read_signers(env: &Env) -> Vec<Address> {
@@ -110,17 +339,73 @@ fn read_label(env: &Env) -> String {
         .unwrap_or(String::from_str(env, "Treasury"))
 }
 
-fn read_request(env: &Env, id: u32) -> WithdrawalRequest {
+fn extend_persistent_ttl(env: &Env, key: &DataKey) {
     env.storage()
-        .instance()
-        .get(&DataKey::Request(id))
-        .unwrap_or_else(|| panic_with_error!(env, MultisigError::RequestNotFound))
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND);
+}
+
+/// Ids under `key` (`OpenRequests` or `OpenProposals`), oldest first.
+fn open_ids(env: &Env, key: &DataKey) -> Vec<u32> {
+    env.storage().instance().get(key).unwrap_or(Vec::new(env))
+}
+
+/// Track `id` as open under `key`. When the list is full, ids for which
+/// `expired` holds are dropped first; panics if that frees no room.
+fn open_id(env: &Env, key: &DataKey, id: u32, expired: impl Fn(u32) -> bool) {
+    let mut ids = open_ids(env, key);
+    if ids.contains(id) {
+        return;
+    }
+    if ids.len() >= MAX_OPEN_REQUESTS {
+        let mut live = Vec::new(env);
+        for open in ids.iter() {
+            if !expired(open) {
+                live.push_back(open);
+            }
+        }
+        ids = live;
+    }
+    if ids.len() >= MAX_OPEN_REQUESTS {
+        panic_with_error!(env, MultisigError::TooManyOpen);
+    }
+    ids.push_back(id);
+    env.storage().instance().set(key, &ids);
+}
+
+fn close_id(env: &Env, key: &DataKey, id: u32) {
+    let mut ids = open_ids(env, key);
+    if let Some(index) = ids.first_index_of(id) {
+        ids.remove(index);
+        env.storage().instance().set(key, &ids);
+    }
+}
+
+fn read_request(env: &Env, id: u32) -> WithdrawalRequest {
+    let key = DataKey::Request(id);
+    let request = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, MultisigError::RequestNotFound));
+    extend_persistent_ttl(env, &key);
+    request
 }
 
+/// Store `request`, keeping it on the open list exactly while it is
+/// pending.
 fn write_request(env: &Env, request: &WithdrawalRequest) {
-    env.storage()
-        .instance()
-        .set(&DataKey::Request(request.id), request);
+    if request.status == RequestStatus::Pending {
+        let now = env.ledger().timestamp();
+        open_id(env, &DataKey::OpenRequests, request.id, |id| {
+            now >= read_request(env, id).expires_at
+        });
+    } else {
+        close_id(env, &DataKey::OpenRequests, request.id);
+    }
+    let key = DataKey::Request(request.id);
+    env.storage().persistent().set(&key, request);
+    extend_persistent_ttl(env, &key);
 }
 
 fn next_request_id(env: &Env) -> u32 {
@@ -137,6 +422,49 @@ fn next_request_id(env: &Env) -> u32 {
     current
 }
 
+fn read_proposal(env: &Env, id: u32) -> GovernanceProposal {
+    let key = DataKey::Proposal(id);
+    let proposal = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or_else(|| panic_with_error!(env, MultisigError::RequestNotFound));
+    extend_persistent_ttl(env, &key);
+    proposal
+}
+
+/// Store `proposal`, keeping it on the open list until it is executed.
+fn write_proposal(env: &Env, proposal: &GovernanceProposal) {
+    if proposal.executed {
+        close_id(env, &DataKey::OpenProposals, proposal.id);
+    } else {
+        let now = env.ledger().timestamp();
+        open_id(env, &DataKey::OpenProposals, proposal.id, |id| {
+            now >= read_proposal(env, id).expires_at
+        });
+    }
+    let key = DataKey::Proposal(proposal.id);
+    env.storage().persistent().set(&key, proposal);
+    extend_persistent_ttl(env, &key);
+}
+
+fn next_proposal_id(env: &Env) -> u32 {
+    let current = env
+        .storage()
+        .instance()
+        .get(&DataKey::NextProposalId)
+        .unwrap_or(0u32);
+    let next = current.checked_add(1).expect("proposal overflow");
+    env.storage()
+        .instance()
+        .set(&DataKey::NextProposalId, &next);
+    current
+}
+
+fn read_vetoer(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Vetoer)
+}
+
 fn validate_signer(env: &Env, signer: &Address) {
     let signers = read_signers(env);
     let allowed = signers.iter().any(|addr| addr == *signer);
@@ -145,28 +473,304 @@ fn validate_signer(env: &Env, signer: &Address) {
     }
 }
 
+fn read_destinations(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Destinations)
+        .unwrap_or(Vec::new(env))
+}
+
 fn ensure_destination_allowed(env: &Env, to: &Address) {
-    let signers = read_signers(env);
-    if !signers.iter().any(|addr| addr == *to) {
+    if !read_destinations(env).contains(to) {
         panic_with_error!(env, MultisigError::DestinationNotAllowed);
     }
 }
 
+fn ensure_asset_allowed(env: &Env, token: &Address) {
+    if !read_assets(env).contains(token) {
+        panic_with_error!(env, MultisigError::AssetNotAllowed);
+    }
+}
+
+fn ensure_within_limit(env: &Env, token: &Address, amount: i128) {
+    let max_amount = read_asset_policy(env, token).and_then(|policy| policy.max_amount);
+    if max_amount.is_some_and(|max| amount > max) {
+        panic_with_error!(env, MultisigError::LimitExceeded);
+    }
+}
+
+/// Approvals needed to withdraw `token`: its own threshold if it has one,
+/// otherwise the treasury's.
+fn asset_threshold(env: &Env, token: &Address) -> u32 {
+    read_asset_policy(env, token)
+        .and_then(|policy| policy.threshold)
+        .unwrap_or_else(|| read_threshold(env))
+}
+
+/// Approvals needed to pass `action`. Changes scoped to one asset need
+/// that asset's threshold too, so a lower treasury threshold cannot be
+/// used to loosen or drop a stricter asset.
+fn proposal_threshold(env: &Env, action: &GovernanceAction) -> u32 {
+    let threshold = read_threshold(env);
+    match action {
+        GovernanceAction::AddAsset(token)
+        | GovernanceAction::RemoveAsset(token)
+        | GovernanceAction::SetAssetPolicy(token, _) => threshold.max(asset_threshold(env, token)),
+        _ => threshold,
+    }
+}
+
+fn request_status(env: &Env, request: &WithdrawalRequest) -> RequestStatus {
+    if request.status == RequestStatus::Pending && env.ledger().timestamp() >= request.expires_at {
+        RequestStatus::Expired
+    } else {
+        request.status
+    }
+}
+
+/// Panic unless `request` is still open for votes.
+fn ensure_pending(env: &Env, request: &WithdrawalRequest) {
+    match request_status(env, request) {
+        RequestStatus::Pending => {}
+        RequestStatus::Expired => panic_with_error!(env, MultisigError::RequestExpired),
+        _ => panic_with_error!(env, MultisigError::RequestClosed),
+    }
+}
+
 fn maybe_execute(env: &Env, request: &mut WithdrawalRequest) -> bool {
-    if request.executed {
+    if request.status == RequestStatus::Executed {
         return true;
     }
-    let threshold = read_threshold(env);
-    if request.approvals.len() < threshold {
+    if request.approvals.len() < asset_threshold(env, &request.token) {
         return false;
     }
+    ensure_asset_allowed(env, &request.token);
+    ensure_within_limit(env, &request.token, request.amount);
     ensure_destination_allowed(env, &request.to);
-    let token = read_token(env);
-    let client = token::Client::new(env, &token);
+    let client = token::Client::new(env, &request.token);
     let self_address = env.current_contract_address();
     client.transfer(&self_address, &request.to, &request.amount);
-    request.executed = true;
+    request.status = RequestStatus::Executed;
     request.completed_at = env.ledger().timestamp();
+    WithdrawalExecuted {
+        id: request.id,
+        token: request.token.clone(),
+        to: request.to.clone(),
+        amount: request.amount,
+    }
+    .publish(env);
+    true
+}
+
+/// Signer set and threshold that would result from `action`, panicking if
+/// the change is invalid against the current configuration.
+fn governed_config(env: &Env, action: &GovernanceAction) -> (Vec<Address>, u32) {
+    let mut signers = read_signers(env);
+    let mut threshold = read_threshold(env);
+    match action {
+        GovernanceAction::AddSigner(signer) => {
+            if signers.contains(signer) {
+                panic_with_error!(env, MultisigError::DuplicateSigner);
+            }
+            signers.push_back(signer.clone());
+        }
+        GovernanceAction::RemoveSigner(signer) => {
+            let Some(index) = signers.first_index_of(signer) else {
+                panic_with_error!(env, MultisigError::SignerNotFound);
+            };
+            signers.remove(index);
+        }
+        GovernanceAction::SetThreshold(value) => threshold = *value,
+        _ => {}
+    }
+    // Per-asset thresholds must stay reachable too
+    let mut thresholds = Vec::from_array(env, [threshold]);
+    for asset in read_assets(env).iter() {
+        if let Some(value) = read_asset_policy(env, &asset).and_then(|policy| policy.threshold) {
+            thresholds.push_back(value);
+        }
+    }
+    if let GovernanceAction::SetAssetPolicy(_, policy) = action {
+        if let Some(value) = policy.threshold {
+            thresholds.push_back(value);
+        }
+    }
+    if thresholds
+        .iter()
+        .any(|value| value == 0 || value > signers.len())
+    {
+        panic_with_error!(env, MultisigError::InvalidThreshold);
+    }
+    (signers, threshold)
+}
+
+/// Asset allowlist that would result from `action`, or `None` when the
+/// action does not touch it. Panics if the change is invalid.
+fn governed_assets(env: &Env, action: &GovernanceAction) -> Option<Vec<Address>> {
+    let mut assets = read_assets(env);
+    match action {
+        GovernanceAction::AddAsset(token) => {
+            if assets.contains(token) {
+                panic_with_error!(env, MultisigError::DuplicateAsset);
+            }
+            assets.push_back(token.clone());
+        }
+        GovernanceAction::RemoveAsset(token) => {
+            let Some(index) = assets.first_index_of(token) else {
+                panic_with_error!(env, MultisigError::AssetNotAllowed);
+            };
+            assets.remove(index);
+        }
+        GovernanceAction::SetAssetPolicy(token, policy) => {
+            ensure_asset_allowed(env, token);
+            if policy.max_amount.is_some_and(|max| max <= 0) {
+                panic_with_error!(env, MultisigError::InvalidAmount);
+            }
+            return None;
+        }
+        _ => return None,
+    }
+    Some(assets)
+}
+
+/// Destination allowlist that would result from `action`, or `None` when
+/// the action does not touch it. Panics if the change is invalid.
+fn governed_destinations(env: &Env, action: &GovernanceAction) -> Option<Vec<Address>> {
+    let mut destinations = read_destinations(env);
+    match action {
+        GovernanceAction::AddDestination(to) => {
+            if destinations.contains(to) {
+                panic_with_error!(env, MultisigError::DuplicateDestination);
+            }
+            destinations.push_back(to.clone());
+        }
+        GovernanceAction::RemoveDestination(to) => {
+            let Some(index) = destinations.first_index_of(to) else {
+                panic_with_error!(env, MultisigError::DestinationNotAllowed);
+            };
+            destinations.remove(index);
+        }
+        _ => return None,
+    }
+    Some(destinations)
+}
+
+/// Strip a removed signer's approvals from everything still open, so
+/// they no longer count towards the threshold.
+fn drop_pending_approvals(env: &Env, signer: &Address) {
+    for id in open_ids(env, &DataKey::OpenRequests).iter() {
+        let mut request = read_request(env, id);
+        let mut changed = false;
+        for votes in [
+            &mut request.approvals,
+            &mut request.cancellations,
+            &mut request.rejections,
+        ] {
+            if let Some(index) = votes.first_index_of(signer) {
+                votes.remove(index);
+                changed = true;
+            }
+        }
+        if changed {
+            write_request(env, &request);
+        }
+    }
+    for id in open_ids(env, &DataKey::OpenProposals).iter() {
+        let mut proposal = read_proposal(env, id);
+        if let Some(index) = proposal.approvals.first_index_of(signer) {
+            proposal.approvals.remove(index);
+            write_proposal(env, &proposal);
+        }
+    }
+}
+
+/// Point the quorum policy's threshold at `threshold`. The treasury is the
+/// direct invoker, which satisfies the policy's own auth check.
+fn set_quorum_threshold(env: &Env, rule_id: u32, threshold: u32) {
+    let Some(policy) = env.storage().instance().get::<_, Address>(&DataKey::QuorumPolicy) else {
+        return;
+    };
+    let args = vec![
+        env,
+        threshold.into_val(env),
+        get_context_rule(env, rule_id).into_val(env),
+        env.current_contract_address().into_val(env),
+    ];
+    env.invoke_contract::<()>(&policy, &Symbol::new(env, "set_threshold"), args);
+}
+
+/// Keep the quorum rule in step with an executed governance change: its
+/// signers follow signer changes, and its policy's threshold follows the
+/// treasury threshold, lowered before a signer is removed so it never
+/// exceeds the signer count.
+fn sync_quorum_rule(env: &Env, action: &GovernanceAction, threshold: u32) {
+    let Some(rule_id) = read_quorum_rule(env) else {
+        return;
+    };
+    match action {
+        GovernanceAction::AddSigner(signer) => {
+            add_signer(env, rule_id, &Signer::Delegated(signer.clone()))
+        }
+        GovernanceAction::RemoveSigner(signer) => {
+            set_quorum_threshold(env, rule_id, threshold);
+            remove_signer(env, rule_id, &Signer::Delegated(signer.clone()))
+        }
+        GovernanceAction::SetThreshold(_) => set_quorum_threshold(env, rule_id, threshold),
+        _ => {}
+    }
+}
+
+fn maybe_execute_proposal(env: &Env, proposal: &mut GovernanceProposal) -> bool {
+    if proposal.executed {
+        return true;
+    }
+    if proposal.approvals.len() < proposal_threshold(env, &proposal.action) {
+        return false;
+    }
+    // Re-validated here: other proposals may have changed the set since
+    let (signers, threshold) = governed_config(env, &proposal.action);
+    let destinations = governed_destinations(env, &proposal.action);
+    let assets = governed_assets(env, &proposal.action);
+    proposal.executed = true;
+    proposal.completed_at = env.ledger().timestamp();
+    write_proposal(env, proposal);
+
+    env.storage().instance().set(&DataKey::Signers, &signers);
+    env.storage()
+        .instance()
+        .set(&DataKey::Threshold, &threshold);
+    if let Some(destinations) = destinations {
+        env.storage()
+            .instance()
+            .set(&DataKey::Destinations, &destinations);
+    }
+    if let Some(assets) = assets {
+        env.storage().instance().set(&DataKey::Assets, &assets);
+    }
+    sync_quorum_rule(env, &proposal.action, threshold);
+    match &proposal.action {
+        GovernanceAction::RemoveSigner(removed) => drop_pending_approvals(env, removed),
+        GovernanceAction::SetVetoer(Some(vetoer)) => {
+            env.storage().instance().set(&DataKey::Vetoer, vetoer)
+        }
+        GovernanceAction::SetVetoer(None) => env.storage().instance().remove(&DataKey::Vetoer),
+        GovernanceAction::RemoveAsset(token) => env
+            .storage()
+            .instance()
+            .remove(&DataKey::AssetPolicy(token.clone())),
+        GovernanceAction::SetAssetPolicy(token, policy) => env
+            .storage()
+            .instance()
+            .set(&DataKey::AssetPolicy(token.clone()), policy),
+        _ => {}
+    }
+    GovernanceExecuted {
+        id: proposal.id,
+        action: proposal.action.clone(),
+        signer_count: signers.len(),
+        threshold,
+    }
+    .publish(env);
     true
 }
 
@@ -175,21 +779,35 @@ fn to_snapshot(env: &Env, request: &WithdrawalRequest) -> WithdrawalSnapshot {
     for signer in request.approvals.iter() {
         approvals.push_back(signer.to_string());
     }
+    let mut cancellations = Vec::new(env);
+    for signer in request.cancellations.iter() {
+        cancellations.push_back(signer.to_string());
+    }
+    let mut rejections = Vec::new(env);
+    for signer in request.rejections.iter() {
+        rejections.push_back(signer.to_string());
+    }
     WithdrawalSnapshot {
         id: request.id,
+        token: request.token.to_string(),
         to: request.to.to_string(),
         amount: request.amount,
         approvals,
-        executed: request.executed,
+        cancellations,
+        rejections,
+        status: request_status(env, request),
         initiator: request.initiator.to_string(),
         created_at: request.created_at,
+        expires_at: request.expires_at,
         completed_at: request.completed_at,
     }
 }
 
 #[contractimpl]
 impl MultisigTreasury {
-    pub fn init(
+    /// Runs once, atomically with deployment, so nobody can initialize the
+    /// treasury ahead of its deployer.
+    pub fn __constructor(
         env: Env,
         admin: Address,
         token: Address,
@@ -197,7 +815,6 @@ impl MultisigTreasury {
         threshold: u32,
         label: String,
     ) {
-        admin.require_auth();
         if signers.is_empty() {
             panic_with_error!(env, MultisigError::InvalidThreshold);
         }
@@ -206,8 +823,12 @@ impl MultisigTreasury {
         }
 
         env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage().instance().set(&DataKey::Token, &token);
+        env.storage()
+            .instance()
+            .set(&DataKey::Assets, &Vec::from_array(&env, [token]));
         env.storage().instance().set(&DataKey::Signers, &signers);
+        // Corridor signers start out as the payable destinations
+        env.storage().instance().set(&DataKey::Destinations, &signers);
         env.storage().instance().set(&DataKey::Threshold, &threshold);
         env.storage().instance().set(&DataKey::Label, &label);
         env.storage()
@@ -228,92 +849,386 @@ impl MultisigTreasury {
     pub fn get_summary(env: Env) -> MultisigSummary {
         let threshold = read_threshold(&env);
         let signers = read_signers(&env);
+        let self_address = env.current_contract_address();
+        let mut balances = Map::new(&env);
+        for asset in read_assets(&env).iter() {
+            let balance = token::Client::new(&env, &asset).balance(&self_address);
+            balances.set(asset, balance);
+        }
         MultisigSummary {
             threshold,
-            signer_count: signers.len() as u32,
+            signer_count: signers.len(),
             label: read_label(&env),
+            balances,
         }
     }
 
+    /// Tokens the treasury may pay out
+    pub fn get_assets(env: Env) -> Vec<Address> {
+        read_assets(&env)
+    }
+
+    pub fn get_asset_policy(env: Env, token: Address) -> Option<AssetPolicy> {
+        read_asset_policy(&env, &token)
+    }
+
     pub fn get_signers(env: Env) -> Vec<Address> {
         read_signers(&env)
     }
 
-    pub fn list_requests(env: Env) -> Vec<WithdrawalSnapshot> {
-        let mut result = Vec::new(&env);
-        let next_id = env
-            .storage()
+    /// Addresses withdrawals may be paid to
+    pub fn get_destinations(env: Env) -> Vec<Address> {
+        read_destinations(&env)
+    }
+
+    pub fn get_vetoer(env: Env) -> Option<Address> {
+        read_vetoer(&env)
+    }
+
+    /// Number of withdrawal requests ever proposed; ids run from 0
+    pub fn request_count(env: Env) -> u32 {
+        env.storage()
             .instance()
-            .get::<_, u32>(&DataKey::NextRequestId)
-            .unwrap_or(0);
-        let mut current = 0u32;
-        while current < next_id {
+            .get(&DataKey::NextRequestId)
+            .unwrap_or(0)
+    }
+
+    /// List withdrawal requests by id from `start`, at most 50 per page.
+    /// Requests whose storage has expired are left out.
+    pub fn list_requests(env: Env, start: u32, limit: u32) -> Vec<WithdrawalSnapshot> {
+        let mut result = Vec::new(&env);
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(Self::request_count(env.clone()));
+        for id in start..end {
             if let Some(request) = env
                 .storage()
-                .instance()
-                .get::<_, WithdrawalRequest>(&DataKey::Request(current))
+                .persistent()
+                .get::<_, WithdrawalRequest>(&DataKey::Request(id))
             {
                 result.push_back(to_snapshot(&env, &request));
             }
-            current = current.saturating_add(1);
         }
         result
     }
 
-    pub fn propose_withdraw(env: Env, signer: Address, to: Address, amount: i128) -> u32 {
-        if amount <= 0 {
-            panic_with_error!(env, MultisigError::InvalidAmount);
+    /// Number of governance proposals ever made; ids run from 0
+    pub fn proposal_count(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextProposalId)
+            .unwrap_or(0)
+    }
+
+    /// List governance proposals by id from `start`, at most 50 per page.
+    /// Proposals whose storage has expired are left out.
+    pub fn list_proposals(env: Env, start: u32, limit: u32) -> Vec<GovernanceProposal> {
+        let mut result = Vec::new(&env);
+        let end = start
+            .saturating_add(limit.min(MAX_PAGE_SIZE))
+            .min(Self::proposal_count(env.clone()));
+        for id in start..end {
+            if let Some(proposal) = env
+                .storage()
+                .persistent()
+                .get::<_, GovernanceProposal>(&DataKey::Proposal(id))
+            {
+                result.push_back(proposal);
+            }
         }
+        result
+    }
+
+    /// Propose a change to the signers, thresholds, vetoer, or the
+    /// destination or asset allowlists. Every threshold, including
+    /// per-asset ones, must stay within `1..=signer count`; the change
+    /// takes effect once the treasury threshold of signers has approved
+    /// (or the asset's, if higher, for `AddAsset`, `RemoveAsset` and
+    /// `SetAssetPolicy`),
+    /// which must happen within `PROPOSAL_TTL_SECONDS`.
+    pub fn propose_governance(env: Env, signer: Address, action: GovernanceAction) -> u32 {
         signer.require_auth();
         validate_signer(&env, &signer);
-        ensure_destination_allowed(&env, &to);
+        governed_config(&env, &action);
+        governed_destinations(&env, &action);
+        governed_assets(&env, &action);
 
-        let id = next_request_id(&env);
-        let approvals = Vec::from_array(&env, [signer.clone()]);
-        let mut request = WithdrawalRequest {
+        let id = next_proposal_id(&env);
+        GovernanceProposed {
             id,
-            to,
-            amount,
-            approvals,
+            initiator: signer.clone(),
+            action: action.clone(),
+            threshold: proposal_threshold(&env, &action),
+        }
+        .publish(&env);
+        let now = env.ledger().timestamp();
+        let mut proposal = GovernanceProposal {
+            id,
+            action,
+            approvals: Vec::from_array(&env, [signer.clone()]),
             executed: false,
             initiator: signer,
-            created_at: env.ledger().timestamp(),
+            created_at: now,
+            expires_at: now.saturating_add(PROPOSAL_TTL_SECONDS),
             completed_at: 0,
         };
-
-        if maybe_execute(&env, &mut request) {
-            request.completed_at = env.ledger().timestamp();
+        if !maybe_execute_proposal(&env, &mut proposal) {
+            write_proposal(&env, &proposal);
         }
-
-        write_request(&env, &request);
         id
     }
 
-    pub fn approve_withdraw(env: Env, signer: Address, request_id: u32) -> bool {
+    pub fn approve_governance(env: Env, signer: Address, proposal_id: u32) -> bool {
         signer.require_auth();
         validate_signer(&env, &signer);
 
-        let mut request = read_request(&env, request_id);
-        if request.executed {
+        let mut proposal = read_proposal(&env, proposal_id);
+        if proposal.executed {
             return true;
         }
-
-        if request.approvals.iter().any(|addr| addr == signer) {
+        if env.ledger().timestamp() >= proposal.expires_at {
+            panic_with_error!(env, MultisigError::RequestExpired);
+        }
+        if proposal.approvals.contains(&signer) {
             panic_with_error!(env, MultisigError::DuplicateApproval);
         }
 
-        request.approvals.push_back(signer);
-        let executed = maybe_execute(&env, &mut request);
-        if executed && request.completed_at == 0 {
-            request.completed_at = env.ledger().timestamp();
+        proposal.approvals.push_back(signer.clone());
+        GovernanceApproved {
+            id: proposal_id,
+            signer,
+            approvals: proposal.approvals.len(),
+            threshold: proposal_threshold(&env, &proposal.action),
+        }
+        .publish(&env);
+        let executed = maybe_execute_proposal(&env, &mut proposal);
+        if !executed {
+            write_proposal(&env, &proposal);
         }
-        write_request(&env, &request);
         executed
     }
-}
 
-#[contractimpl]
-impl SmartAccount for MultisigTreasury {
+    pub fn propose_withdraw(
+        env: Env,
+        signer: Address,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> u32 {
+        if amount <= 0 {
+            panic_with_error!(env, MultisigError::InvalidAmount);
+        }
+        signer.require_auth();
+        validate_signer(&env, &signer);
+        ensure_asset_allowed(&env, &token);
+        ensure_within_limit(&env, &token, amount);
+        ensure_destination_allowed(&env, &to);
+
+        let id = next_request_id(&env);
+        let approvals = Vec::from_array(&env, [signer.clone()]);
+        WithdrawalProposed {
+            id,
+            initiator: signer.clone(),
+            token: token.clone(),
+            to: to.clone(),
+            amount,
+            threshold: asset_threshold(&env, &token),
+        }
+        .publish(&env);
+        let now = env.ledger().timestamp();
+        let mut request = WithdrawalRequest {
+            id,
+            token,
+            to,
+            amount,
+            approvals,
+            cancellations: Vec::new(&env),
+            rejections: Vec::new(&env),
+            status: RequestStatus::Pending,
+            initiator: signer,
+            created_at: now,
+            expires_at: now.saturating_add(REQUEST_TTL_SECONDS),
+            completed_at: 0,
+        };
+
+        if maybe_execute(&env, &mut request) {
+            request.completed_at = env.ledger().timestamp();
+        }
+
+        write_request(&env, &request);
+        id
+    }
+
+    /// Install a rule over the current signers, scoped to calls on the
+    /// treasury itself, where `policy` (a threshold policy exposing
+    /// `set_threshold`) decides how many of them must sign. `AddSigner` and
+    /// `RemoveSigner` proposals update the rule's signers as they execute,
+    /// and `SetThreshold` and `RemoveSigner` set the policy's threshold to
+    /// the treasury's. A weighted policy is therefore only kept in step
+    /// when every signer weighs 1.
+    ///
+    /// The admin's Default rule is removed in the same call, so from then
+    /// on every call on the treasury, `withdraw` and rule management
+    /// included, needs the quorum. Only one quorum rule can be installed.
+    pub fn install_signer_rule(env: Env, policy: Address, policy_params: Val) -> ContextRule {
+        let self_address = env.current_contract_address();
+        self_address.require_auth();
+        if read_quorum_rule(&env).is_some() {
+            panic_with_error!(env, MultisigError::QuorumRuleInstalled);
+        }
+        let mut signers = Vec::new(&env);
+        for signer in read_signers(&env).iter() {
+            signers.push_back(Signer::Delegated(signer));
+        }
+        let policies = Map::from_array(&env, [(policy.clone(), policy_params)]);
+        let rule = add_context_rule(
+            &env,
+            &ContextRuleType::CallContract(self_address),
+            &String::from_str(&env, "Signer Quorum"),
+            None,
+            &signers,
+            &policies,
+        );
+        env.storage().instance().set(&DataKey::QuorumRule, &rule.id);
+        env.storage().instance().set(&DataKey::QuorumPolicy, &policy);
+        for admin_rule in get_context_rules(&env, &ContextRuleType::Default).iter() {
+            remove_context_rule(&env, admin_rule.id);
+        }
+        rule
+    }
+
+    /// Withdraw in a single call, authorized through `__check_auth` by the
+    /// treasury's context rules rather than stored approvals. Until
+    /// `install_signer_rule` has run, the admin's Default rule alone can
+    /// authorize it. Assets with their own threshold can only be withdrawn
+    /// through propose/approve.
+    pub fn withdraw(env: Env, token: Address, to: Address, amount: i128) {
+        if amount <= 0 {
+            panic_with_error!(env, MultisigError::InvalidAmount);
+        }
+        env.current_contract_address().require_auth();
+        ensure_asset_allowed(&env, &token);
+        if read_asset_policy(&env, &token).is_some_and(|policy| policy.threshold.is_some()) {
+            panic_with_error!(env, MultisigError::ApprovalRequired);
+        }
+        ensure_within_limit(&env, &token, amount);
+        ensure_destination_allowed(&env, &to);
+        let client = token::Client::new(&env, &token);
+        client.transfer(&env.current_contract_address(), &to, &amount);
+        WithdrawalAuthorized { to, token, amount }.publish(&env);
+    }
+
+    pub fn approve_withdraw(env: Env, signer: Address, request_id: u32) -> bool {
+        signer.require_auth();
+        validate_signer(&env, &signer);
+
+        let mut request = read_request(&env, request_id);
+        if request.status == RequestStatus::Executed {
+            return true;
+        }
+        ensure_pending(&env, &request);
+
+        if request.approvals.contains(&signer) || request.rejections.contains(&signer) {
+            panic_with_error!(env, MultisigError::DuplicateApproval);
+        }
+
+        request.approvals.push_back(signer.clone());
+        WithdrawalApproved {
+            id: request_id,
+            signer,
+            token: request.token.clone(),
+            to: request.to.clone(),
+            amount: request.amount,
+            approvals: request.approvals.len(),
+            threshold: asset_threshold(&env, &request.token),
+        }
+        .publish(&env);
+        let executed = maybe_execute(&env, &mut request);
+        if executed && request.completed_at == 0 {
+            request.completed_at = env.ledger().timestamp();
+        }
+        write_request(&env, &request);
+        executed
+    }
+
+    /// Cancel a pending withdrawal. The initiator cancels outright; any
+    /// other signer adds a cancellation vote, and the request closes once
+    /// the asset's threshold is reached. Returns whether the request is cancelled.
+    pub fn cancel_withdraw(env: Env, signer: Address, request_id: u32) -> bool {
+        signer.require_auth();
+        validate_signer(&env, &signer);
+
+        let mut request = read_request(&env, request_id);
+        if request.status == RequestStatus::Cancelled {
+            return true;
+        }
+        ensure_pending(&env, &request);
+        if request.cancellations.contains(&signer) {
+            panic_with_error!(env, MultisigError::DuplicateApproval);
+        }
+
+        request.cancellations.push_back(signer.clone());
+        let threshold = asset_threshold(&env, &request.token);
+        let cancelled = signer == request.initiator || request.cancellations.len() >= threshold;
+        if cancelled {
+            request.status = RequestStatus::Cancelled;
+            request.completed_at = env.ledger().timestamp();
+            WithdrawalCancelled {
+                id: request_id,
+                signer,
+                cancellations: request.cancellations.len(),
+            }
+            .publish(&env);
+        }
+        write_request(&env, &request);
+        cancelled
+    }
+
+    /// Vote against a pending withdrawal. The request closes as `Rejected`
+    /// once more signers have rejected it than could be spared while still
+    /// meeting the threshold, or straight away when `signer` is the vetoer.
+    /// Returns whether the request is closed.
+    pub fn reject_withdraw(env: Env, signer: Address, request_id: u32) -> bool {
+        signer.require_auth();
+        let vetoed = read_vetoer(&env).is_some_and(|vetoer| vetoer == signer);
+        if !vetoed {
+            validate_signer(&env, &signer);
+        }
+
+        let mut request = read_request(&env, request_id);
+        if request.status == RequestStatus::Rejected {
+            return true;
+        }
+        ensure_pending(&env, &request);
+        // A vetoer may veto a request they approved, e.g. as its initiator
+        let approved = !vetoed && request.approvals.contains(&signer);
+        if approved || request.rejections.contains(&signer) {
+            panic_with_error!(env, MultisigError::DuplicateApproval);
+        }
+
+        request.rejections.push_back(signer.clone());
+        let spare = read_signers(&env)
+            .len()
+            .saturating_sub(asset_threshold(&env, &request.token));
+        let closed = vetoed || request.rejections.len() > spare;
+        if closed {
+            request.status = RequestStatus::Rejected;
+            request.completed_at = env.ledger().timestamp();
+        }
+        WithdrawalRejected {
+            id: request_id,
+            signer,
+            rejections: request.rejections.len(),
+            closed,
+        }
+        .publish(&env);
+        write_request(&env, &request);
+        closed
+    }
+}
+
+#[contractimpl]
+impl SmartAccount for MultisigTreasury {
     fn add_context_rule(
         env: &Env,
         context_type: ContextRuleType,
@@ -351,6 +1266,10 @@ impl SmartAccount for MultisigTreasury {
     fn remove_context_rule(env: &Env, context_rule_id: u32) {
         env.current_contract_address().require_auth();
         remove_context_rule(env, context_rule_id);
+        if read_quorum_rule(env) == Some(context_rule_id) {
+            env.storage().instance().remove(&DataKey::QuorumRule);
+            env.storage().instance().remove(&DataKey::QuorumPolicy);
+        }
     }
 
     fn add_signer(env: &Env, context_rule_id: u32, signer: Signer) {
@@ -371,6 +1290,10 @@ impl SmartAccount for MultisigTreasury {
     fn remove_policy(env: &Env, context_rule_id: u32, policy: Address) {
         env.current_contract_address().require_auth();
         remove_policy(env, context_rule_id, &policy);
+        let quorum_policy = env.storage().instance().get::<_, Address>(&DataKey::QuorumPolicy);
+        if read_quorum_rule(env) == Some(context_rule_id) && quorum_policy == Some(policy) {
+            env.storage().instance().remove(&DataKey::QuorumPolicy);
+        }
     }
 }
 
@@ -392,9 +1315,14 @@ impl CustomAccountInterface for MultisigTreasury {
 #[cfg(test)]
 mod test {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use simple_threshold_policy::{
+        SimpleThresholdAccountParams, SimpleThresholdPolicy, SimpleThresholdPolicyClient,
+    };
+    use soroban_sdk::auth::ContractContext;
+    use soroban_sdk::testutils::{Address as _, BytesN as _, Events, Ledger};
     use soroban_sdk::token::{Client as TokenClient, StellarAssetClient as TokenAdminClient};
-    use soroban_sdk::{vec, Address, Env, String};
+    use soroban_sdk::{map, vec, Address, Bytes, BytesN, Env, Event, IntoVal, String, Symbol};
+    use weighted_threshold_policy::{WeightedThresholdAccountParams, WeightedThresholdPolicy};
 
     fn create_token_contract<'a>(
         env: &Env,
@@ -422,21 +1350,22 @@ mod test {
         let token_admin = Address::generate(env);
         let (token_client, token_admin_client) = create_token_contract(env, &token_admin);
 
-        let contract_id = env.register(MultisigTreasury, ());
-        let client = MultisigTreasuryClient::new(env, &contract_id);
-
         let signer_a = Address::generate(env);
         let signer_b = Address::generate(env);
         let signer_c = Address::generate(env);
         let signers = vec![env, signer_a.clone(), signer_b.clone(), signer_c.clone()];
 
-        client.init(
-            &admin,
-            &token_client.address.clone(),
-            &signers,
-            &2,
-            &String::from_str(env, "Treasury"),
+        let contract_id = env.register(
+            MultisigTreasury,
+            (
+                admin,
+                token_client.address.clone(),
+                signers.clone(),
+                2u32,
+                String::from_str(env, "Treasury"),
+            ),
         );
+        let client = MultisigTreasuryClient::new(env, &contract_id);
 
         token_admin_client.mint(&contract_id, &1_000);
 
@@ -451,28 +1380,42 @@ mod test {
     }
 
     #[test]
-    fn init_rejects_invalid_threshold() {
+    #[should_panic(expected = "Error(Contract, #2)")]
+    fn constructor_rejects_invalid_threshold() {
         let env = Env::default();
-        env.mock_all_auths();
         let admin = Address::generate(&env);
         let token = Address::generate(&env);
-        let signer_a = Address::generate(&env);
-        let signers = vec![&env, signer_a];
+        let signers = vec![&env, Address::generate(&env)];
 
-        let contract_id = env.register(MultisigTreasury, ());
-        let client = MultisigTreasuryClient::new(&env, &contract_id);
-
-        let res = client.try_init(
-            &admin,
-            &token,
-            &signers,
-            &0,
-            &String::from_str(&env, "bad"),
+        env.register(
+            MultisigTreasury,
+            (admin, token, signers, 0u32, String::from_str(&env, "bad")),
         );
-        assert_eq!(
-            res.err(),
-            Some(Ok(MultisigError::InvalidThreshold.into()))
+    }
+
+    #[test]
+    fn constructor_cannot_be_reinvoked() {
+        let env = Env::default();
+        let (client, contract_id, signers, _recipient, token_client, _) = setup_multisig(&env);
+        let attacker = Address::generate(&env);
+
+        let res = env.try_invoke_contract::<(), soroban_sdk::Error>(
+            &contract_id,
+            &Symbol::new(&env, "__constructor"),
+            (
+                attacker,
+                token_client.address.clone(),
+                signers,
+                1u32,
+                String::from_str(&env, "Hijacked"),
+            )
+                .into_val(&env),
         );
+        assert!(res.is_err());
+
+        let summary = client.get_summary();
+        assert_eq!(summary.threshold, 2);
+        assert_eq!(summary.label, String::from_str(&env, "Treasury"));
     }
 
     #[test]
@@ -483,7 +1426,7 @@ mod test {
         let signer2 = signers.get(2).unwrap();
 
         // First signer proposes withdrawal
-        let request_id = client.propose_withdraw(&signer1, &recipient, &500);
+        let request_id = client.propose_withdraw(&signer1, &token_client.address, &recipient, &500);
         assert_eq!(token_client.balance(&contract_id), 1_000);
         assert_eq!(token_client.balance(&recipient), 0);
 
@@ -494,21 +1437,249 @@ mod test {
         assert_eq!(token_client.balance(&recipient), 500);
 
         // Request should be marked executed in snapshot
-        let snapshots = client.list_requests();
+        let snapshots = client.list_requests(&0, &MAX_PAGE_SIZE);
         assert_eq!(snapshots.len(), 1);
         let snapshot = snapshots.get(0).unwrap();
-        assert!(snapshot.executed);
+        assert_eq!(snapshot.status, RequestStatus::Executed);
         assert_eq!(snapshot.approvals.len(), 2);
     }
 
+    fn contains_event(env: &Env, contract_id: &Address, event: impl Event) -> bool {
+        env.events()
+            .all()
+            .contains((contract_id.clone(), event.topics(env), event.data(env)))
+    }
+
+    #[test]
+    fn withdrawal_lifecycle_emits_events() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer1 = signers.get(0).unwrap();
+        let signer2 = signers.get(2).unwrap();
+
+        let request_id = client.propose_withdraw(&signer1, &token_client.address, &recipient, &250);
+        assert!(contains_event(
+            &env,
+            &contract_id,
+            WithdrawalProposed {
+                id: request_id,
+                initiator: signer1.clone(),
+                token: token_client.address.clone(),
+                to: recipient.clone(),
+                amount: 250,
+                threshold: 2,
+            }
+        ));
+
+        client.approve_withdraw(&signer2, &request_id);
+        assert!(contains_event(
+            &env,
+            &contract_id,
+            WithdrawalApproved {
+                id: request_id,
+                signer: signer2.clone(),
+                token: token_client.address.clone(),
+                to: recipient.clone(),
+                amount: 250,
+                approvals: 2,
+                threshold: 2,
+            }
+        ));
+        assert!(contains_event(
+            &env,
+            &contract_id,
+            WithdrawalExecuted {
+                id: request_id,
+                token: token_client.address.clone(),
+                to: recipient.clone(),
+                amount: 250,
+            }
+        ));
+    }
+
+    fn quorum_authorizes(
+        env: &Env,
+        contract_id: &Address,
+        signers: Vec<Address>,
+        token: &Address,
+        to: &Address,
+        amount: i128,
+    ) -> bool {
+        let mut signatures = Map::new(env);
+        for signer in signers.iter() {
+            signatures.set(Signer::Delegated(signer), Bytes::new(env));
+        }
+        let context = Context::Contract(ContractContext {
+            contract: contract_id.clone(),
+            fn_name: Symbol::new(env, "withdraw"),
+            args: (token.clone(), to.clone(), amount).into_val(env),
+        });
+        env.try_invoke_contract_check_auth::<SmartAccountError>(
+            contract_id,
+            &BytesN::random(env),
+            Signatures(signatures).into_val(env),
+            &vec![env, context],
+        )
+        .is_ok()
+    }
+
+    #[test]
+    fn simple_threshold_policy_authorizes_native_withdrawal() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let policy = env.register(SimpleThresholdPolicy, ());
+        let params = SimpleThresholdAccountParams { threshold: 2 };
+        client.install_signer_rule(&policy, &params.into_val(&env));
+
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        assert!(!quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, signer_a.clone()],
+            &token_client.address,
+            &recipient,
+            400
+        ));
+        assert!(quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, signer_a, signer_b],
+            &token_client.address,
+            &recipient,
+            400
+        ));
+
+        client.withdraw(&token_client.address, &recipient, &400);
+        assert_eq!(token_client.balance(&recipient), 400);
+        assert_eq!(token_client.balance(&contract_id), 600);
+    }
+
+    #[test]
+    fn quorum_rule_replaces_admin_rule() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let admin_rule = client
+            .get_context_rules(&ContextRuleType::Default)
+            .get(0)
+            .unwrap();
+        let Signer::Delegated(admin) = admin_rule.signers.get(0).unwrap() else {
+            panic!("admin rule has a delegated signer");
+        };
+
+        // Before a quorum rule exists the admin alone can withdraw
+        assert!(quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, admin.clone()],
+            &token_client.address,
+            &recipient,
+            400
+        ));
+
+        let policy = env.register(SimpleThresholdPolicy, ());
+        let params = SimpleThresholdAccountParams { threshold: 2 };
+        client.install_signer_rule(&policy, &params.into_val(&env));
+        assert!(client
+            .get_context_rules(&ContextRuleType::Default)
+            .is_empty());
+        assert!(!quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, admin],
+            &token_client.address,
+            &recipient,
+            400
+        ));
+
+        let res = client.try_install_signer_rule(&policy, &params.into_val(&env));
+        assert_eq!(res.err(), Some(Ok(MultisigError::QuorumRuleInstalled.into())));
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        assert!(quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, signer_a, signer_b],
+            &token_client.address,
+            &recipient,
+            400
+        ));
+    }
+
+    #[test]
+    fn weighted_threshold_policy_authorizes_native_withdrawal() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_c = signers.get(2).unwrap();
+
+        let policy = env.register(WeightedThresholdPolicy, ());
+        let params = WeightedThresholdAccountParams {
+            signer_weights: map![
+                &env,
+                (Signer::Delegated(signer_a.clone()), 2),
+                (Signer::Delegated(signer_b.clone()), 1),
+                (Signer::Delegated(signer_c.clone()), 1)
+            ],
+            threshold: 2,
+        };
+        client.install_signer_rule(&policy, &params.into_val(&env));
+
+        assert!(quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, signer_a],
+            &token_client.address,
+            &recipient,
+            100
+        ));
+        assert!(!quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, signer_b.clone()],
+            &token_client.address,
+            &recipient,
+            100
+        ));
+        assert!(quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, signer_b, signer_c],
+            &token_client.address,
+            &recipient,
+            100
+        ));
+    }
+
+    #[test]
+    fn requests_are_listed_in_pages() {
+        let env = Env::default();
+        let (client, _contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer = signers.get(0).unwrap();
+        for amount in [10, 20, 30] {
+            client.propose_withdraw(&signer, &token_client.address, &recipient, &amount);
+        }
+        assert_eq!(client.request_count(), 3);
+
+        let page = client.list_requests(&1, &1);
+        assert_eq!(page.len(), 1);
+        assert_eq!(page.get(0).unwrap().id, 1);
+        assert_eq!(client.list_requests(&2, &10).len(), 1);
+        assert_eq!(client.list_requests(&3, &10).len(), 0);
+        assert_eq!(client.list_requests(&0, &u32::MAX).len(), 3);
+        assert_eq!(client.proposal_count(), 0);
+        assert_eq!(client.list_proposals(&0, &MAX_PAGE_SIZE).len(), 0);
+    }
+
     #[test]
     fn duplicate_approval_rejected() {
         let env = Env::default();
-        let (client, _contract_id, signers, recipient, _token_client, _) = setup_multisig(&env);
+        let (client, _contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
         let signer1 = signers.get(0).unwrap();
         let signer2 = signers.get(2).unwrap();
 
-        let request_id = client.propose_withdraw(&signer1, &recipient, &100);
+        let request_id = client.propose_withdraw(&signer1, &token_client.address, &recipient, &100);
         let dup_result = client.try_approve_withdraw(&signer1, &request_id);
         assert_eq!(
             dup_result.err(),
@@ -517,4 +1688,561 @@ mod test {
 
         assert!(client.approve_withdraw(&signer2, &request_id));
     }
+
+    #[test]
+    fn governance_adds_signer_and_raises_threshold() {
+        let env = Env::default();
+        let (client, contract_id, signers, _recipient, _token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_d = Address::generate(&env);
+
+        let add = GovernanceAction::AddSigner(signer_d.clone());
+        let id = client.propose_governance(&signer_a, &add);
+        assert_eq!(client.get_summary().signer_count, 3);
+        assert!(client.approve_governance(&signer_b, &id));
+        assert!(contains_event(
+            &env,
+            &contract_id,
+            GovernanceExecuted {
+                id,
+                action: add,
+                signer_count: 4,
+                threshold: 2,
+            }
+        ));
+        assert_eq!(client.get_signers().last().unwrap(), signer_d);
+
+        // The new signer votes straight away
+        let raise = client.propose_governance(&signer_d, &GovernanceAction::SetThreshold(3));
+        assert!(client.approve_governance(&signer_a, &raise));
+        let summary = client.get_summary();
+        assert_eq!(summary.threshold, 3);
+        assert_eq!(summary.signer_count, 4);
+        assert!(client.list_proposals(&0, &MAX_PAGE_SIZE).iter().all(|p| p.executed));
+    }
+
+    #[test]
+    fn governance_signer_changes_follow_into_quorum_rule() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_d = Address::generate(&env);
+        let policy = env.register(SimpleThresholdPolicy, ());
+        let params = SimpleThresholdAccountParams { threshold: 2 };
+        let rule = client.install_signer_rule(&policy, &params.into_val(&env));
+
+        let add =
+            client.propose_governance(&signer_a, &GovernanceAction::AddSigner(signer_d.clone()));
+        client.approve_governance(&signer_b, &add);
+        let signer = Signer::Delegated(signer_d.clone());
+        assert!(client.get_context_rule(&rule.id).signers.contains(signer));
+        assert!(quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, signer_a.clone(), signer_d.clone()],
+            &token_client.address,
+            &recipient,
+            100
+        ));
+
+        let remove =
+            client.propose_governance(&signer_a, &GovernanceAction::RemoveSigner(signer_b.clone()));
+        client.approve_governance(&signer_d, &remove);
+        let rule = client.get_context_rule(&rule.id);
+        assert!(!rule.signers.contains(Signer::Delegated(signer_b.clone())));
+        assert!(!quorum_authorizes(
+            &env,
+            &contract_id,
+            vec![&env, signer_a, signer_b],
+            &token_client.address,
+            &recipient,
+            100
+        ));
+    }
+
+    #[test]
+    fn governance_threshold_changes_follow_into_quorum_rule() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_c = signers.get(2).unwrap();
+        let policy = env.register(SimpleThresholdPolicy, ());
+        let policy_client = SimpleThresholdPolicyClient::new(&env, &policy);
+        let params = SimpleThresholdAccountParams { threshold: 2 };
+        let rule = client.install_signer_rule(&policy, &params.into_val(&env));
+
+        // Raising the treasury threshold raises the policy's with it
+        let raise = client.propose_governance(&signer_a, &GovernanceAction::SetThreshold(3));
+        client.approve_governance(&signer_b, &raise);
+        assert_eq!(policy_client.get_threshold(&rule.id, &contract_id), 3);
+        let pair = vec![&env, signer_a.clone(), signer_b.clone()];
+        assert!(!quorum_authorizes(
+            &env,
+            &contract_id,
+            pair.clone(),
+            &token_client.address,
+            &recipient,
+            100
+        ));
+        assert!(quorum_authorizes(
+            &env,
+            &contract_id,
+            signers.clone(),
+            &token_client.address,
+            &recipient,
+            100
+        ));
+
+        let lower = client.propose_governance(&signer_a, &GovernanceAction::SetThreshold(2));
+        client.approve_governance(&signer_b, &lower);
+        client.approve_governance(&signer_c, &lower);
+        assert_eq!(policy_client.get_threshold(&rule.id, &contract_id), 2);
+
+        // Even when the policy was set to 3 out of band, removing a signer
+        // brings it back to the treasury's 2 first
+        let current = client.get_context_rule(&rule.id);
+        policy_client.set_threshold(&3, &current, &contract_id);
+        let remove =
+            client.propose_governance(&signer_a, &GovernanceAction::RemoveSigner(signer_c));
+        client.approve_governance(&signer_b, &remove);
+        assert_eq!(policy_client.get_threshold(&rule.id, &contract_id), 2);
+        assert_eq!(client.get_context_rule(&rule.id).signers.len(), 2);
+        assert!(quorum_authorizes(
+            &env,
+            &contract_id,
+            pair,
+            &token_client.address,
+            &recipient,
+            100
+        ));
+    }
+
+    #[test]
+    fn expired_proposal_rejects_approval() {
+        let env = Env::default();
+        let (client, _contract_id, signers, _recipient, _token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+
+        let id = client.propose_governance(&signer_a, &GovernanceAction::SetThreshold(3));
+        let proposal = client.list_proposals(&0, &MAX_PAGE_SIZE).get(id).unwrap();
+        assert_eq!(proposal.expires_at, proposal.created_at + PROPOSAL_TTL_SECONDS);
+
+        env.ledger().with_mut(|li| li.timestamp = proposal.expires_at);
+        let res = client.try_approve_governance(&signer_b, &id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::RequestExpired.into())));
+        assert_eq!(client.get_summary().threshold, 2);
+    }
+
+    #[test]
+    fn open_requests_are_bounded_until_they_expire() {
+        let env = Env::default();
+        let (client, _contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let token = token_client.address.clone();
+
+        for _ in 0..MAX_OPEN_REQUESTS {
+            client.propose_withdraw(&signer_a, &token, &recipient, &1);
+        }
+        let res = client.try_propose_withdraw(&signer_a, &token, &recipient, &1);
+        assert_eq!(res.err(), Some(Ok(MultisigError::TooManyOpen.into())));
+
+        // Executing one frees its slot
+        assert!(client.approve_withdraw(&signer_b, &0));
+        client.propose_withdraw(&signer_a, &token, &recipient, &1);
+
+        // Expired requests make room once the list is full
+        env.ledger().with_mut(|li| li.timestamp = REQUEST_TTL_SECONDS);
+        let id = client.propose_withdraw(&signer_a, &token, &recipient, &1);
+        assert!(client.approve_withdraw(&signer_b, &id));
+        assert_eq!(token_client.balance(&recipient), 2);
+    }
+
+    #[test]
+    fn governance_keeps_threshold_within_signer_count() {
+        let env = Env::default();
+        let (client, _contract_id, signers, _recipient, _token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_c = signers.get(2).unwrap();
+
+        let res = client.try_propose_governance(&signer_a, &GovernanceAction::SetThreshold(4));
+        assert_eq!(res.err(), Some(Ok(MultisigError::InvalidThreshold.into())));
+        let res = client.try_propose_governance(&signer_a, &GovernanceAction::SetThreshold(0));
+        assert_eq!(res.err(), Some(Ok(MultisigError::InvalidThreshold.into())));
+        let res = client.try_propose_governance(
+            &signer_a,
+            &GovernanceAction::AddSigner(signer_b.clone()),
+        );
+        assert_eq!(res.err(), Some(Ok(MultisigError::DuplicateSigner.into())));
+        let res = client.try_propose_governance(
+            &signer_a,
+            &GovernanceAction::RemoveSigner(Address::generate(&env)),
+        );
+        assert_eq!(res.err(), Some(Ok(MultisigError::SignerNotFound.into())));
+
+        // Each removal is valid alone, but the second would leave one
+        // signer under a threshold of two
+        let first = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::RemoveSigner(signer_b.clone()),
+        );
+        let second = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::RemoveSigner(signer_c.clone()),
+        );
+        assert!(client.approve_governance(&signer_c, &first));
+        let res = client.try_approve_governance(&signer_c, &second);
+        assert_eq!(res.err(), Some(Ok(MultisigError::InvalidThreshold.into())));
+        assert_eq!(client.get_summary().signer_count, 2);
+    }
+
+    #[test]
+    fn removed_signer_approvals_stop_counting() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_c = signers.get(2).unwrap();
+        let signer_d = Address::generate(&env);
+
+        let add = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::AddSigner(signer_d.clone()),
+        );
+        client.approve_governance(&signer_c, &add);
+
+        // signer_c backs a withdrawal, then loses their seat
+        let request_id = client.propose_withdraw(
+            &signer_c,
+            &token_client.address,
+            &recipient,
+            &300,
+        );
+        let remove = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::RemoveSigner(signer_c.clone()),
+        );
+        assert!(client.approve_governance(&signer_b, &remove));
+
+        let request = client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap();
+        assert!(request.approvals.is_empty());
+        let res = client.try_approve_withdraw(&signer_c, &request_id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::SignerNotAllowed.into())));
+
+        // One current signer is not enough on their own
+        assert!(!client.approve_withdraw(&signer_a, &request_id));
+        assert_eq!(token_client.balance(&contract_id), 1_000);
+        assert!(client.approve_withdraw(&signer_b, &request_id));
+        assert_eq!(token_client.balance(&recipient), 300);
+    }
+
+    #[test]
+    fn expired_request_rejects_approval() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer1 = signers.get(0).unwrap();
+        let signer2 = signers.get(2).unwrap();
+
+        let request_id = client.propose_withdraw(&signer1, &token_client.address, &recipient, &100);
+        let snapshot = client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap();
+        assert_eq!(snapshot.status, RequestStatus::Pending);
+        assert_eq!(snapshot.expires_at, snapshot.created_at + REQUEST_TTL_SECONDS);
+
+        env.ledger().with_mut(|li| li.timestamp = snapshot.expires_at);
+        let res = client.try_approve_withdraw(&signer2, &request_id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::RequestExpired.into())));
+        let res = client.try_cancel_withdraw(&signer1, &request_id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::RequestExpired.into())));
+        assert_eq!(
+            client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap().status,
+            RequestStatus::Expired
+        );
+        assert_eq!(token_client.balance(&contract_id), 1_000);
+    }
+
+    #[test]
+    fn initiator_cancels_outright() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer1 = signers.get(0).unwrap();
+        let signer2 = signers.get(2).unwrap();
+
+        let request_id = client.propose_withdraw(&signer1, &token_client.address, &recipient, &100);
+        assert!(client.cancel_withdraw(&signer1, &request_id));
+        assert!(contains_event(
+            &env,
+            &contract_id,
+            WithdrawalCancelled {
+                id: request_id,
+                signer: signer1.clone(),
+                cancellations: 1,
+            }
+        ));
+
+        let snapshot = client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap();
+        assert_eq!(snapshot.status, RequestStatus::Cancelled);
+        let res = client.try_approve_withdraw(&signer2, &request_id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::RequestClosed.into())));
+    }
+
+    #[test]
+    fn threshold_of_signers_cancels() {
+        let env = Env::default();
+        let (client, _contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_c = signers.get(2).unwrap();
+
+        let request_id = client.propose_withdraw(
+            &signer_a,
+            &token_client.address,
+            &recipient,
+            &100,
+        );
+        assert!(!client.cancel_withdraw(&signer_b, &request_id));
+        let res = client.try_cancel_withdraw(&signer_b, &request_id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::DuplicateApproval.into())));
+        assert_eq!(
+            client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap().status,
+            RequestStatus::Pending
+        );
+
+        assert!(client.cancel_withdraw(&signer_c, &request_id));
+        let snapshot = client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap();
+        assert_eq!(snapshot.status, RequestStatus::Cancelled);
+        assert_eq!(snapshot.cancellations.len(), 2);
+    }
+
+    #[test]
+    fn rejections_close_request_once_it_cannot_pass() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_c = signers.get(2).unwrap();
+
+        // 2-of-3 can spare one rejection
+        let request_id = client.propose_withdraw(
+            &signer_a,
+            &token_client.address,
+            &recipient,
+            &100,
+        );
+        assert!(!client.reject_withdraw(&signer_b, &request_id));
+        let res = client.try_approve_withdraw(&signer_b, &request_id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::DuplicateApproval.into())));
+
+        assert!(client.reject_withdraw(&signer_c, &request_id));
+        assert!(contains_event(
+            &env,
+            &contract_id,
+            WithdrawalRejected {
+                id: request_id,
+                signer: signer_c.clone(),
+                rejections: 2,
+                closed: true,
+            }
+        ));
+        let snapshot = client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap();
+        assert_eq!(snapshot.status, RequestStatus::Rejected);
+        assert_eq!(snapshot.rejections.len(), 2);
+        let res = client.try_approve_withdraw(&signer_c, &request_id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::RequestClosed.into())));
+    }
+
+    #[test]
+    fn vetoer_blocks_request_outright() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let compliance = Address::generate(&env);
+
+        let request_id = client.propose_withdraw(
+            &signer_a,
+            &token_client.address,
+            &recipient,
+            &100,
+        );
+        let res = client.try_reject_withdraw(&compliance, &request_id);
+        assert_eq!(res.err(), Some(Ok(MultisigError::SignerNotAllowed.into())));
+
+        let grant = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::SetVetoer(Some(compliance.clone())),
+        );
+        client.approve_governance(&signer_b, &grant);
+        assert_eq!(client.get_vetoer(), Some(compliance.clone()));
+
+        assert!(client.reject_withdraw(&compliance, &request_id));
+        assert_eq!(
+            client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap().status,
+            RequestStatus::Rejected
+        );
+        assert_eq!(token_client.balance(&contract_id), 1_000);
+
+        let revoke = client.propose_governance(&signer_a, &GovernanceAction::SetVetoer(None));
+        client.approve_governance(&signer_b, &revoke);
+        assert_eq!(client.get_vetoer(), None);
+    }
+
+    #[test]
+    fn vetoer_can_veto_a_request_they_approved() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+
+        let grant = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::SetVetoer(Some(signer_a.clone())),
+        );
+        client.approve_governance(&signer_b, &grant);
+
+        // The initiator's proposal counts as their approval
+        let request_id = client.propose_withdraw(
+            &signer_a,
+            &token_client.address,
+            &recipient,
+            &100,
+        );
+        assert!(client.reject_withdraw(&signer_a, &request_id));
+        assert_eq!(
+            client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap().status,
+            RequestStatus::Rejected
+        );
+        assert_eq!(token_client.balance(&contract_id), 1_000);
+    }
+
+    #[test]
+    fn governed_destinations_decouple_payees_from_signers() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, token_client, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let cold_wallet = Address::generate(&env);
+        assert_eq!(client.get_destinations(), signers);
+
+        let res = client.try_propose_withdraw(&signer_a, &token_client.address, &cold_wallet, &100);
+        assert_eq!(res.err(), Some(Ok(MultisigError::DestinationNotAllowed.into())));
+
+        let allow = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::AddDestination(cold_wallet.clone()),
+        );
+        client.approve_governance(&signer_b, &allow);
+        let request_id = client.propose_withdraw(
+            &signer_a,
+            &token_client.address,
+            &cold_wallet,
+            &100,
+        );
+        assert!(client.approve_withdraw(&signer_b, &request_id));
+        assert_eq!(token_client.balance(&cold_wallet), 100);
+
+        // A signer stays a signer after it stops being a payee
+        let pending = client.propose_withdraw(&signer_a, &token_client.address, &recipient, &100);
+        let remove = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::RemoveDestination(recipient.clone()),
+        );
+        client.approve_governance(&signer_b, &remove);
+        assert!(!client.get_destinations().contains(&recipient));
+        let res = client.try_approve_withdraw(&recipient, &pending);
+        assert_eq!(res.err(), Some(Ok(MultisigError::DestinationNotAllowed.into())));
+        assert_eq!(token_client.balance(&contract_id), 900);
+
+        let res = client.try_propose_governance(
+            &signer_a,
+            &GovernanceAction::AddDestination(cold_wallet.clone()),
+        );
+        assert_eq!(res.err(), Some(Ok(MultisigError::DuplicateDestination.into())));
+    }
+
+    #[test]
+    fn treasury_pays_out_each_allowed_asset_under_its_policy() {
+        let env = Env::default();
+        let (client, contract_id, signers, recipient, usdc, _) = setup_multisig(&env);
+        let signer_a = signers.get(0).unwrap();
+        let signer_b = signers.get(1).unwrap();
+        let signer_c = signers.get(2).unwrap();
+        let (eurc, eurc_admin) = create_token_contract(&env, &Address::generate(&env));
+        eurc_admin.mint(&contract_id, &5_000);
+
+        let res = client.try_propose_withdraw(&signer_a, &eurc.address, &recipient, &100);
+        assert_eq!(res.err(), Some(Ok(MultisigError::AssetNotAllowed.into())));
+
+        let add = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::AddAsset(eurc.address.clone()),
+        );
+        client.approve_governance(&signer_b, &add);
+        let policy = AssetPolicy {
+            threshold: Some(3),
+            max_amount: Some(2_000),
+        };
+        let set = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::SetAssetPolicy(eurc.address.clone(), policy.clone()),
+        );
+        client.approve_governance(&signer_b, &set);
+        assert_eq!(client.get_asset_policy(&eurc.address), Some(policy));
+
+        let summary = client.get_summary();
+        assert_eq!(summary.balances.get(usdc.address.clone()), Some(1_000));
+        assert_eq!(summary.balances.get(eurc.address.clone()), Some(5_000));
+
+        let res = client.try_propose_withdraw(&signer_a, &eurc.address, &recipient, &2_001);
+        assert_eq!(res.err(), Some(Ok(MultisigError::LimitExceeded.into())));
+
+        // EURC needs all three signers, USDC still two
+        let request_id = client.propose_withdraw(&signer_a, &eurc.address, &recipient, &2_000);
+        assert!(!client.approve_withdraw(&signer_b, &request_id));
+        assert!(client.approve_withdraw(&signer_c, &request_id));
+        assert_eq!(eurc.balance(&recipient), 2_000);
+        let snapshot = client.list_requests(&0, &MAX_PAGE_SIZE).get(request_id).unwrap();
+        assert_eq!(snapshot.token, eurc.address.to_string());
+
+        let request_id = client.propose_withdraw(&signer_a, &usdc.address, &recipient, &500);
+        assert!(client.approve_withdraw(&signer_b, &request_id));
+        assert_eq!(usdc.balance(&recipient), 500);
+
+        // EURC's own threshold cannot be met on the native path
+        let res = client.try_withdraw(&eurc.address, &recipient, &100);
+        assert_eq!(res.err(), Some(Ok(MultisigError::ApprovalRequired.into())));
+        client.withdraw(&usdc.address, &recipient, &100);
+        assert_eq!(usdc.balance(&recipient), 600);
+
+        // Dropping a signer would leave the EURC threshold unreachable
+        let res = client.try_propose_governance(
+            &signer_a,
+            &GovernanceAction::RemoveSigner(signer_c.clone()),
+        );
+        assert_eq!(res.err(), Some(Ok(MultisigError::InvalidThreshold.into())));
+
+        // Changing EURC's policy takes EURC's threshold, not the treasury's
+        let pending = client.propose_withdraw(&signer_a, &eurc.address, &recipient, &1_500);
+        let tighter = AssetPolicy {
+            threshold: Some(3),
+            max_amount: Some(1_000),
+        };
+        let set = client.propose_governance(
+            &signer_a,
+            &GovernanceAction::SetAssetPolicy(eurc.address.clone(), tighter.clone()),
+        );
+        assert!(!client.approve_governance(&signer_b, &set));
+        assert!(client.approve_governance(&signer_c, &set));
+        assert_eq!(client.get_asset_policy(&eurc.address), Some(tighter));
+
+        // A request opened under the old cap cannot execute above the new one
+        client.approve_withdraw(&signer_b, &pending);
+        let res = client.try_approve_withdraw(&signer_c, &pending);
+        assert_eq!(res.err(), Some(Ok(MultisigError::LimitExceeded.into())));
+    }
 }
+
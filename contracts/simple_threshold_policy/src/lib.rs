@@ -0,0 +1,91 @@
+#![no_std]
+
+//! M-of-N threshold policy for OpenZeppelin smart-account context rules.
+//!
+//! Installed on a rule that lists N signers, it lets the rule authorize a
+//! context once at least `threshold` of them have signed, instead of
+//! requiring every listed signer. The treasury uses it to turn a rule over
+//! the corridor accounts into a native M-of-N quorum.
+//!
+//! One deployment can serve any number of smart accounts; thresholds are
+//! stored per `(smart_account, context_rule_id)`.
+
+use soroban_sdk::auth::Context;
+use soroban_sdk::{contract, contractimpl, Address, Env, Vec};
+use stellar_accounts::policies::{simple_threshold, Policy};
+use stellar_accounts::smart_account::{ContextRule, Signer};
+
+pub use stellar_accounts::policies::simple_threshold::{
+    SimpleThresholdAccountParams, SimpleThresholdError,
+};
+
+#[contract]
+pub struct SimpleThresholdPolicy;
+
+#[contractimpl]
+impl SimpleThresholdPolicy {
+    /// Change the threshold of an installed rule; must be authorized by the account
+    pub fn set_threshold(
+        env: Env,
+        threshold: u32,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        simple_threshold::set_threshold(&env, threshold, &context_rule, &smart_account);
+    }
+
+    /// Threshold configured for an installed rule
+    pub fn get_threshold(env: Env, context_rule_id: u32, smart_account: Address) -> u32 {
+        simple_threshold::get_threshold(&env, context_rule_id, &smart_account)
+    }
+}
+
+#[contractimpl]
+impl Policy for SimpleThresholdPolicy {
+    type AccountParams = SimpleThresholdAccountParams;
+
+    fn can_enforce(
+        env: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        simple_threshold::can_enforce(
+            env,
+            &context,
+            &authenticated_signers,
+            &context_rule,
+            &smart_account,
+        )
+    }
+
+    fn enforce(
+        env: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        simple_threshold::enforce(
+            env,
+            &context,
+            &authenticated_signers,
+            &context_rule,
+            &smart_account,
+        );
+    }
+
+    fn install(
+        env: &Env,
+        install_params: SimpleThresholdAccountParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        simple_threshold::install(env, &install_params, &context_rule, &smart_account);
+    }
+
+    fn uninstall(env: &Env, context_rule: ContextRule, smart_account: Address) {
+        simple_threshold::uninstall(env, &context_rule, &smart_account);
+    }
+}
@@ -0,0 +1,223 @@
+#![no_std]
+
+//! Swap adapter that fills RemittanceAccount forex swaps through a Soroswap
+//! router.
+//!
+//! Register it with `add_swap_adapter` on the account. Adapters for other
+//! DEXes (Aquarius, Phoenix, ...) are separate contracts exposing the same
+//! `quote` / `swap` interface; the account routes every swap through the
+//! adapter quoting the most output.
+//!
+//! The account transfers the input tokens to the adapter before calling
+//! `swap`, so the adapter swaps out of its own balance and forwards the
+//! proceeds. It holds no funds between transactions.
+
+use soroban_sdk::auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation};
+use soroban_sdk::{
+    contract, contracterror, contractimpl, panic_with_error, symbol_short, token, vec, Address,
+    Env, IntoVal, Symbol, Vec,
+};
+
+const ROUTER_KEY: Symbol = symbol_short!("router");
+
+#[allow(dead_code)]
+mod soroswap_router {
+    use soroban_sdk::{contractclient, Address, Env, Vec};
+
+    #[contractclient(name = "SoroswapRouterClient")]
+    pub trait SoroswapRouterContract {
+        fn router_get_amounts_out(env: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128>;
+        fn router_pair_for(env: Env, token_a: Address, token_b: Address) -> Address;
+        fn swap_exact_tokens_for_tokens(
+            env: Env,
+            amount_in: i128,
+            amount_out_min: i128,
+            path: Vec<Address>,
+            to: Address,
+            deadline: u64,
+        ) -> Vec<i128>;
+    }
+}
+
+use soroswap_router::SoroswapRouterClient;
+
+#[contracterror]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AdapterError {
+    InvalidPath = 1,
+    SwapFailed = 2,
+}
+
+#[contract]
+pub struct SoroswapAdapter;
+
+fn read_router(env: &Env) -> Address {
+    env.storage().instance().get(&ROUTER_KEY).unwrap()
+}
+
+fn last_amount(env: &Env, amounts: &Vec<i128>) -> i128 {
+    if amounts.len() < 2 {
+        panic_with_error!(env, AdapterError::SwapFailed);
+    }
+    amounts
+        .last()
+        .unwrap_or_else(|| panic_with_error!(env, AdapterError::SwapFailed))
+}
+
+#[contractimpl]
+impl SoroswapAdapter {
+    pub fn __constructor(env: Env, router: Address) {
+        env.storage().instance().set(&ROUTER_KEY, &router);
+    }
+
+    pub fn get_router(env: Env) -> Address {
+        read_router(&env)
+    }
+
+    /// Output Soroswap would give for `amount_in` along `path`
+    pub fn quote(env: Env, amount_in: i128, path: Vec<Address>) -> i128 {
+        let router = SoroswapRouterClient::new(&env, &read_router(&env));
+        last_amount(&env, &router.router_get_amounts_out(&amount_in, &path))
+    }
+
+    /// Swap `amount_in` of `path[0]`, already held by the adapter, and send
+    /// the output to `to`
+    pub fn swap(
+        env: Env,
+        amount_in: i128,
+        min_amount_out: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> i128 {
+        if path.len() < 2 {
+            panic_with_error!(&env, AdapterError::InvalidPath);
+        }
+        let input_token = path.get_unchecked(0);
+        let output_token = path.get_unchecked(path.len() - 1);
+        let router = SoroswapRouterClient::new(&env, &read_router(&env));
+        let self_address = env.current_contract_address();
+
+        // The router moves the input from us into the first pair
+        let pair = router.router_pair_for(&input_token, &path.get_unchecked(1));
+        env.authorize_as_current_contract(vec![
+            &env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: input_token,
+                    fn_name: symbol_short!("transfer"),
+                    args: (self_address.clone(), pair, amount_in).into_val(&env),
+                },
+                sub_invocations: vec![&env],
+            }),
+        ]);
+        let amounts = router.swap_exact_tokens_for_tokens(
+            &amount_in,
+            &min_amount_out,
+            &path,
+            &self_address,
+            &deadline,
+        );
+        let amount_out = last_amount(&env, &amounts);
+        if amount_out < min_amount_out {
+            panic_with_error!(&env, AdapterError::SwapFailed);
+        }
+
+        token::Client::new(&env, &output_token).transfer(&self_address, &to, &amount_out);
+        amount_out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+
+    // Router standing in for Soroswap: pays 2 output per input, pulling the
+    // input from `to` into a fixed pair address and minting the output.
+    #[contract]
+    struct MockSoroswapRouter;
+
+    #[contractimpl]
+    impl MockSoroswapRouter {
+        pub fn __constructor(env: Env, pair: Address) {
+            env.storage().instance().set(&symbol_short!("pair"), &pair);
+        }
+
+        pub fn router_get_amounts_out(env: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128> {
+            let mut amounts = vec![&env, amount_in];
+            for _ in 1..path.len() {
+                amounts.push_back(amounts.last_unchecked() * 2);
+            }
+            amounts
+        }
+
+        pub fn router_pair_for(env: Env, _token_a: Address, _token_b: Address) -> Address {
+            env.storage()
+                .instance()
+                .get(&symbol_short!("pair"))
+                .unwrap()
+        }
+
+        pub fn swap_exact_tokens_for_tokens(
+            env: Env,
+            amount_in: i128,
+            amount_out_min: i128,
+            path: Vec<Address>,
+            to: Address,
+            _deadline: u64,
+        ) -> Vec<i128> {
+            to.require_auth();
+            let amounts = Self::router_get_amounts_out(env.clone(), amount_in, path.clone());
+            let amount_out = amounts.last_unchecked();
+            if amount_out < amount_out_min {
+                panic!("insufficient output amount");
+            }
+            let pair = Self::router_pair_for(env.clone(), to.clone(), to.clone());
+            TokenClient::new(&env, &path.get_unchecked(0)).transfer(&to, &pair, &amount_in);
+            StellarAssetClient::new(&env, &path.last_unchecked()).mint(&to, &amount_out);
+            amounts
+        }
+    }
+
+    #[test]
+    fn swap_forwards_router_output() {
+        let env = Env::default();
+        let pair = Address::generate(&env);
+        let recipient = Address::generate(&env);
+        let router = env.register(MockSoroswapRouter, (pair.clone(),));
+        let adapter = env.register(SoroswapAdapter, (router.clone(),));
+        let client = SoroswapAdapterClient::new(&env, &adapter);
+
+        let input = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let output = env.register_stellar_asset_contract_v2(router).address();
+        env.mock_all_auths();
+        StellarAssetClient::new(&env, &input).mint(&adapter, &100);
+
+        // Only the adapter's own contract authorization is available here
+        env.set_auths(&[]);
+        let path = vec![&env, input.clone(), output.clone()];
+        assert_eq!(client.quote(&100, &path), 200);
+        assert_eq!(client.swap(&100, &190, &path, &recipient, &0), 200);
+
+        assert_eq!(TokenClient::new(&env, &input).balance(&pair), 100);
+        assert_eq!(TokenClient::new(&env, &input).balance(&adapter), 0);
+        assert_eq!(TokenClient::new(&env, &output).balance(&recipient), 200);
+        assert_eq!(TokenClient::new(&env, &output).balance(&adapter), 0);
+    }
+
+    #[test]
+    fn swap_rejects_short_path() {
+        let env = Env::default();
+        let router = env.register(MockSoroswapRouter, (Address::generate(&env),));
+        let adapter = env.register(SoroswapAdapter, (router,));
+        let client = SoroswapAdapterClient::new(&env, &adapter);
+
+        let token = Address::generate(&env);
+        let result = client.try_swap(&1, &1, &vec![&env, token], &Address::generate(&env), &0);
+        assert_eq!(result.err(), Some(Ok(AdapterError::InvalidPath.into())));
+    }
+}
@@ -0,0 +1,467 @@
+#![no_std]
+
+//! Spending-limit policy for OpenZeppelin smart-account context rules.
+//!
+//! Attach it with `SmartAccount::add_policy` (or in the `policies` map of
+//! `add_context_rule`) to cap how much a rule may move through token
+//! `transfer` contexts within a rolling window of ledgers. A rule scoped to
+//! `ContextRuleType::CallContract(token)` with the relayer as its only signer
+//! and this policy installed gives the relayer a capped allowance, while an
+//! admin rule without policies stays unrestricted.
+//!
+//! Remittance accounts authorize their own payout entry points rather than
+//! the token `transfer` they make, so calls to `execute_transfer`,
+//! `execute_token_transfer`, `execute_payment` and `execute_batch_transfer`
+//! on the smart account itself are counted as transfers of their amount (the
+//! batch total for batches). Scope the relayer rule to
+//! `ContextRuleType::CallContract(account)` to cap those payouts. The
+//! allowance is denominated in the `token` given at install, which must be
+//! the account's primary token: `execute_transfer` and
+//! `execute_batch_transfer` always pay in it, and `execute_token_transfer`
+//! or `execute_payment` only count when they pay in it too. Payouts in other
+//! tokens and any other call on the account, forex swaps included, are
+//! outside the allowance and the rule does not apply to them.
+//!
+//! One deployment can serve any number of smart accounts; limits are stored
+//! per `(smart_account, context_rule_id)`.
+
+use soroban_sdk::auth::{Context, ContractContext};
+use soroban_sdk::{
+    contract, contractimpl, contracttype, symbol_short, Address, Env, IntoVal, Symbol, TryFromVal,
+    Val, Vec,
+};
+use stellar_accounts::policies::spending_limit::{
+    SpendingLimitAccountParams, SPENDING_LIMIT_EXTEND_AMOUNT, SPENDING_LIMIT_TTL_THRESHOLD,
+};
+use stellar_accounts::policies::{spending_limit, Policy};
+use stellar_accounts::smart_account::{ContextRule, Signer};
+
+pub use stellar_accounts::policies::spending_limit::{SpendingLimitData, SpendingLimitError};
+
+/// Install parameters: the cap, its rolling window and the token it is
+/// denominated in
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutLimitParams {
+    pub spending_limit: i128,
+    pub period_ledgers: u32,
+    pub token: Address,
+}
+
+#[contracttype]
+enum DataKey {
+    /// Token of the allowance installed on `(smart_account, context_rule_id)`
+    Token(Address, u32),
+}
+
+#[contract]
+pub struct SpendingLimitPolicy;
+
+fn arg<T: TryFromVal<Env, Val>>(env: &Env, args: &Vec<Val>, index: u32) -> Option<T> {
+    T::try_from_val(env, &args.get(index)?).ok()
+}
+
+fn read_token(env: &Env, context_rule_id: u32, smart_account: &Address) -> Option<Address> {
+    let key = DataKey::Token(smart_account.clone(), context_rule_id);
+    let token = env.storage().persistent().get(&key)?;
+    env.storage().persistent().extend_ttl(
+        &key,
+        SPENDING_LIMIT_TTL_THRESHOLD,
+        SPENDING_LIMIT_EXTEND_AMOUNT,
+    );
+    Some(token)
+}
+
+/// The context the stock spending-limit logic should see: remittance
+/// payouts on the smart account in the rule's token are rewritten as the
+/// `transfer` they make, other calls on the account yield `None`, and
+/// everything else (token transfers included) is passed through unchanged.
+fn as_transfer(
+    env: &Env,
+    context: &Context,
+    context_rule: &ContextRule,
+    smart_account: &Address,
+) -> Option<Context> {
+    let Context::Contract(ContractContext {
+        contract,
+        fn_name,
+        args,
+    }) = context
+    else {
+        return Some(context.clone());
+    };
+    if contract != smart_account {
+        return Some(context.clone());
+    }
+
+    let (to, amount) = if *fn_name == Symbol::new(env, "execute_transfer") {
+        (arg::<Address>(env, args, 0)?, arg::<i128>(env, args, 1)?)
+    } else if *fn_name == Symbol::new(env, "execute_token_transfer")
+        || *fn_name == Symbol::new(env, "execute_payment")
+    {
+        let token = read_token(env, context_rule.id, smart_account)?;
+        if arg::<Address>(env, args, 0)? != token {
+            return None;
+        }
+        (arg::<Address>(env, args, 1)?, arg::<i128>(env, args, 2)?)
+    } else if *fn_name == Symbol::new(env, "execute_batch_transfer") {
+        let mut total: i128 = 0;
+        for (_, amount) in arg::<Vec<(Address, i128)>>(env, args, 0)?.iter() {
+            total = total.checked_add(amount)?;
+        }
+        (smart_account.clone(), total)
+    } else {
+        return None;
+    };
+
+    Some(Context::Contract(ContractContext {
+        contract: contract.clone(),
+        fn_name: symbol_short!("transfer"),
+        args: (smart_account.clone(), to, amount).into_val(env),
+    }))
+}
+
+#[contractimpl]
+impl SpendingLimitPolicy {
+    /// Change the cap of an installed rule; must be authorized by the account
+    pub fn set_spending_limit(
+        env: Env,
+        spending_limit: i128,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        spending_limit::set_spending_limit(&env, spending_limit, &context_rule, &smart_account);
+    }
+
+    /// Limit, period and spend history for an installed rule
+    pub fn get_spending_limit_data(
+        env: Env,
+        context_rule_id: u32,
+        smart_account: Address,
+    ) -> SpendingLimitData {
+        spending_limit::get_spending_limit_data(&env, context_rule_id, &smart_account)
+    }
+}
+
+#[contractimpl]
+impl Policy for SpendingLimitPolicy {
+    type AccountParams = PayoutLimitParams;
+
+    fn can_enforce(
+        env: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        let Some(context) = as_transfer(env, &context, &context_rule, &smart_account) else {
+            return false;
+        };
+        spending_limit::can_enforce(
+            env,
+            &context,
+            &authenticated_signers,
+            &context_rule,
+            &smart_account,
+        )
+    }
+
+    fn enforce(
+        env: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        let context = as_transfer(env, &context, &context_rule, &smart_account).unwrap_or(context);
+        spending_limit::enforce(
+            env,
+            &context,
+            &authenticated_signers,
+            &context_rule,
+            &smart_account,
+        );
+    }
+
+    fn install(
+        env: &Env,
+        install_params: PayoutLimitParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        let params = SpendingLimitAccountParams {
+            spending_limit: install_params.spending_limit,
+            period_ledgers: install_params.period_ledgers,
+        };
+        spending_limit::install(env, &params, &context_rule, &smart_account);
+        env.storage().persistent().set(
+            &DataKey::Token(smart_account, context_rule.id),
+            &install_params.token,
+        );
+    }
+
+    fn uninstall(env: &Env, context_rule: ContextRule, smart_account: Address) {
+        spending_limit::uninstall(env, &context_rule, &smart_account);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::Token(smart_account, context_rule.id));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use soroban_sdk::auth::{ContractContext, CustomAccountInterface};
+    use soroban_sdk::crypto::Hash;
+    use soroban_sdk::testutils::{Address as _, BytesN as _, Ledger};
+    use soroban_sdk::token::StellarAssetClient as TokenAdminClient;
+    use soroban_sdk::{map, vec, Bytes, BytesN, Map, String, TryIntoVal};
+    use stellar_accounts::smart_account::{
+        add_context_rule, do_check_auth, ContextRuleType, Signatures, SmartAccountError,
+    };
+
+    // Minimal smart account exposing just enough to install rules and run
+    // `__check_auth` the same way the remittance and treasury contracts do.
+    #[contract]
+    struct TestAccount;
+
+    #[contractimpl]
+    impl TestAccount {
+        pub fn add_rule(
+            env: Env,
+            context_type: ContextRuleType,
+            signers: Vec<Signer>,
+            policies: Map<Address, Val>,
+        ) -> ContextRule {
+            add_context_rule(
+                &env,
+                &context_type,
+                &String::from_str(&env, "rule"),
+                None,
+                &signers,
+                &policies,
+            )
+        }
+    }
+
+    #[contractimpl]
+    impl CustomAccountInterface for TestAccount {
+        type Signature = Signatures;
+        type Error = SmartAccountError;
+        fn __check_auth(
+            env: Env,
+            signature_payload: Hash<32>,
+            signatures: Signatures,
+            auth_contexts: Vec<Context>,
+        ) -> Result<(), SmartAccountError> {
+            do_check_auth(&env, &signature_payload, &signatures, &auth_contexts)
+        }
+    }
+
+    struct Setup<'a> {
+        env: Env,
+        account: Address,
+        token: Address,
+        policy: SpendingLimitPolicyClient<'a>,
+        admin: Address,
+        relayer: Address,
+        relayer_rule: ContextRule,
+    }
+
+    fn setup<'a>() -> Setup<'a> {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let relayer = Address::generate(&env);
+        let token_admin = Address::generate(&env);
+        let token = env
+            .register_stellar_asset_contract_v2(token_admin)
+            .address();
+        let account = env.register(TestAccount, ());
+        let policy_id = env.register(SpendingLimitPolicy, ());
+        let account_client = TestAccountClient::new(&env, &account);
+
+        // Unrestricted admin rule plus a relayer rule capped at 500 per 100 ledgers
+        account_client.add_rule(
+            &ContextRuleType::Default,
+            &vec![&env, Signer::Delegated(admin.clone())],
+            &Map::new(&env),
+        );
+        let params = PayoutLimitParams {
+            spending_limit: 500,
+            period_ledgers: 100,
+            token: token.clone(),
+        };
+        let relayer_rule = account_client.add_rule(
+            &ContextRuleType::CallContract(token.clone()),
+            &vec![&env, Signer::Delegated(relayer.clone())],
+            &map![&env, (policy_id.clone(), params.into_val(&env))],
+        );
+
+        TokenAdminClient::new(&env, &token).mint(&account, &10_000);
+
+        Setup {
+            policy: SpendingLimitPolicyClient::new(&env, &policy_id),
+            env,
+            account,
+            token,
+            admin,
+            relayer,
+            relayer_rule,
+        }
+    }
+
+    fn transfer_context(s: &Setup, amount: i128) -> Context {
+        let to = Address::generate(&s.env);
+        Context::Contract(ContractContext {
+            contract: s.token.clone(),
+            fn_name: symbol_short!("transfer"),
+            args: (s.account.clone(), to, amount).into_val(&s.env),
+        })
+    }
+
+    fn account_context(s: &Setup, fn_name: &str, args: Vec<Val>) -> Context {
+        Context::Contract(ContractContext {
+            contract: s.account.clone(),
+            fn_name: Symbol::new(&s.env, fn_name),
+            args,
+        })
+    }
+
+    fn check_auth(s: &Setup, signer: &Address, amount: i128) -> bool {
+        check_auth_context(s, signer, transfer_context(s, amount))
+    }
+
+    fn check_auth_context(s: &Setup, signer: &Address, context: Context) -> bool {
+        let signatures = Signatures(map![
+            &s.env,
+            (Signer::Delegated(signer.clone()), Bytes::new(&s.env))
+        ]);
+        s.env
+            .try_invoke_contract_check_auth::<SmartAccountError>(
+                &s.account,
+                &BytesN::random(&s.env),
+                signatures.try_into_val(&s.env).unwrap(),
+                &vec![&s.env, context],
+            )
+            .is_ok()
+    }
+
+    #[test]
+    fn install_records_limit() {
+        let s = setup();
+        let data = s
+            .policy
+            .get_spending_limit_data(&s.relayer_rule.id, &s.account);
+        assert_eq!(data.spending_limit, 500);
+        assert_eq!(data.period_ledgers, 100);
+        assert_eq!(data.cached_total_spent, 0);
+    }
+
+    #[test]
+    fn relayer_is_capped_while_admin_is_not() {
+        let s = setup();
+
+        assert!(check_auth(&s, &s.relayer, 300));
+        assert!(check_auth(&s, &s.relayer, 200));
+        // 500 already spent in this period
+        assert!(!check_auth(&s, &s.relayer, 1));
+
+        // The admin rule carries no policy
+        assert!(check_auth(&s, &s.admin, 5_000));
+
+        let data = s
+            .policy
+            .get_spending_limit_data(&s.relayer_rule.id, &s.account);
+        assert_eq!(data.cached_total_spent, 500);
+    }
+
+    #[test]
+    fn allowance_recovers_after_period() {
+        let s = setup();
+
+        assert!(check_auth(&s, &s.relayer, 500));
+        assert!(!check_auth(&s, &s.relayer, 100));
+
+        s.env.ledger().with_mut(|li| li.sequence_number += 101);
+        assert!(check_auth(&s, &s.relayer, 100));
+    }
+
+    #[test]
+    fn set_spending_limit_updates_cap() {
+        let s = setup();
+
+        assert!(check_auth(&s, &s.relayer, 500));
+        s.policy
+            .set_spending_limit(&800, &s.relayer_rule, &s.account);
+        assert!(check_auth(&s, &s.relayer, 300));
+        assert!(!check_auth(&s, &s.relayer, 1));
+    }
+
+    #[test]
+    fn account_payouts_count_against_limit() {
+        let s = setup();
+        let payout_signer = Address::generate(&s.env);
+        let params = PayoutLimitParams {
+            spending_limit: 500,
+            period_ledgers: 100,
+            token: s.token.clone(),
+        };
+        TestAccountClient::new(&s.env, &s.account).add_rule(
+            &ContextRuleType::CallContract(s.account.clone()),
+            &vec![&s.env, Signer::Delegated(payout_signer.clone())],
+            &map![&s.env, (s.policy.address.clone(), params.into_val(&s.env))],
+        );
+        let to = Address::generate(&s.env);
+
+        let payout = account_context(
+            &s,
+            "execute_transfer",
+            (to.clone(), 200_i128).into_val(&s.env),
+        );
+        assert!(check_auth_context(&s, &payout_signer, payout));
+
+        // Payouts in another token are outside the allowance, so the rule
+        // does not authorize them at all
+        let other_token = Address::generate(&s.env);
+        let payout = account_context(
+            &s,
+            "execute_token_transfer",
+            (other_token, to.clone(), 100_i128).into_val(&s.env),
+        );
+        assert!(!check_auth_context(&s, &payout_signer, payout));
+
+        let batch = vec![&s.env, (to.clone(), 100_i128), (to.clone(), 150_i128)];
+        let payout = account_context(&s, "execute_batch_transfer", (batch,).into_val(&s.env));
+        assert!(check_auth_context(&s, &payout_signer, payout));
+        // 450 already spent in this period
+        let payout = account_context(
+            &s,
+            "execute_token_transfer",
+            (s.token.clone(), to.clone(), 51_i128).into_val(&s.env),
+        );
+        assert!(!check_auth_context(&s, &payout_signer, payout));
+        let payout = account_context(
+            &s,
+            "execute_payment",
+            (
+                s.token.clone(),
+                to.clone(),
+                50_i128,
+                None::<BytesN<32>>,
+                None::<String>,
+            )
+                .into_val(&s.env),
+        );
+        assert!(check_auth_context(&s, &payout_signer, payout));
+
+        // Calls that are not payouts are outside the rule
+        let other = account_context(
+            &s,
+            "set_label",
+            (String::from_str(&s.env, "x"),).into_val(&s.env),
+        );
+        assert!(!check_auth_context(&s, &payout_signer, other));
+    }
+}
@@ -0,0 +1,62 @@
+use soroban_sdk::{contractclient, Address, Env, Vec};
+
+use crate::DataKey;
+
+/// Most swap adapters an account may register.
+pub const MAX_ADAPTERS: u32 = 5;
+
+/// Interface every swap adapter contract implements, whatever DEX sits
+/// behind it. The account transfers `amount_in` of `path[0]` to the
+/// adapter before calling `swap`; the adapter must send at least
+/// `min_amount_out` of the last path token to `to` and return the amount
+/// sent.
+#[allow(dead_code)]
+#[contractclient(name = "SwapAdapterClient")]
+pub trait SwapAdapter {
+    /// Output the adapter expects for swapping `amount_in` along `path`.
+    fn quote(env: Env, amount_in: i128, path: Vec<Address>) -> i128;
+
+    fn swap(
+        env: Env,
+        amount_in: i128,
+        min_amount_out: i128,
+        path: Vec<Address>,
+        to: Address,
+        deadline: u64,
+    ) -> i128;
+}
+
+pub fn read(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::SwapAdapters)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn write(env: &Env, adapters: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::SwapAdapters, adapters);
+}
+
+/// The adapter quoting the most output for `amount_in` along `path`, with
+/// its quote. Adapters that fail to quote, or quote nothing, are skipped.
+pub fn best_quote(env: &Env, amount_in: i128, path: &Vec<Address>) -> Option<(Address, i128)> {
+    let mut best: Option<(Address, i128)> = None;
+    for adapter in read(env).iter() {
+        let client = SwapAdapterClient::new(env, &adapter);
+        let Ok(Ok(quote)) = client.try_quote(&amount_in, path) else {
+            continue;
+        };
+        if quote <= 0 {
+            continue;
+        }
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_quote)| quote > *best_quote)
+        {
+            best = Some((adapter, quote));
+        }
+    }
+    best
+}
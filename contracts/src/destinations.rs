@@ -0,0 +1,144 @@
+use soroban_sdk::{contracttype, Address, Env, String, Vec};
+
+use crate::{extend_persistent_ttl, DataKey, RemittanceError};
+
+/// Largest page returned by `list_destinations`.
+pub const MAX_PAGE_SIZE: u32 = 50;
+
+/// A registered payee and its metadata.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Destination {
+    pub address: Address,
+    pub label: String,
+    /// Corridor or ISO country code, e.g. "GB" or "US-AR".
+    pub corridor: String,
+    pub preferred_token: Option<Address>,
+    pub enabled: bool,
+}
+
+impl Destination {
+    pub fn new(env: &Env, address: Address) -> Self {
+        Destination {
+            address,
+            label: String::from_str(env, ""),
+            corridor: String::from_str(env, ""),
+            preferred_token: None,
+            enabled: true,
+        }
+    }
+}
+
+pub fn count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DestinationCount)
+        .unwrap_or(0)
+}
+
+fn set_count(env: &Env, count: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DestinationCount, &count);
+}
+
+pub fn read(env: &Env, address: &Address) -> Option<Destination> {
+    let key = DataKey::Destination(address.clone());
+    let destination = env.storage().persistent().get::<_, Destination>(&key);
+    if destination.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    destination
+}
+
+/// Insert a new destination or overwrite the metadata of an existing one.
+pub fn write(env: &Env, destination: &Destination) {
+    let key = DataKey::Destination(destination.address.clone());
+    if !env.storage().persistent().has(&key) {
+        let index = count(env);
+        let index_key = DataKey::DestinationIndex(destination.address.clone());
+        let slot_key = DataKey::DestinationAt(index);
+        env.storage().persistent().set(&index_key, &index);
+        env.storage()
+            .persistent()
+            .set(&slot_key, &destination.address);
+        extend_persistent_ttl(env, &index_key);
+        extend_persistent_ttl(env, &slot_key);
+        set_count(env, index + 1);
+    }
+    env.storage().persistent().set(&key, destination);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Remove a destination, moving the last entry into its slot so the index
+/// stays dense.
+pub fn remove(env: &Env, address: &Address) -> Result<(), RemittanceError> {
+    let index_key = DataKey::DestinationIndex(address.clone());
+    let index = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&index_key)
+        .ok_or(RemittanceError::DestinationNotFound)?;
+    let last = count(env) - 1;
+    if index != last {
+        let moved = env
+            .storage()
+            .persistent()
+            .get::<_, Address>(&DataKey::DestinationAt(last))
+            .ok_or(RemittanceError::DestinationNotFound)?;
+        env.storage()
+            .persistent()
+            .set(&DataKey::DestinationAt(index), &moved);
+        env.storage()
+            .persistent()
+            .set(&DataKey::DestinationIndex(moved), &index);
+    }
+    env.storage()
+        .persistent()
+        .remove(&DataKey::DestinationAt(last));
+    env.storage().persistent().remove(&index_key);
+    env.storage()
+        .persistent()
+        .remove(&DataKey::Destination(address.clone()));
+    set_count(env, last);
+    Ok(())
+}
+
+pub fn clear(env: &Env) {
+    let mut index = count(env);
+    while index > 0 {
+        index -= 1;
+        let slot_key = DataKey::DestinationAt(index);
+        if let Some(address) = env.storage().persistent().get::<_, Address>(&slot_key) {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::DestinationIndex(address.clone()));
+            env.storage()
+                .persistent()
+                .remove(&DataKey::Destination(address));
+        }
+        env.storage().persistent().remove(&slot_key);
+    }
+    set_count(env, 0);
+}
+
+pub fn page(env: &Env, start: u32, limit: u32) -> Vec<Destination> {
+    let mut result = Vec::new(env);
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(count(env));
+    let mut index = start;
+    while index < end {
+        if let Some(address) = env
+            .storage()
+            .persistent()
+            .get::<_, Address>(&DataKey::DestinationAt(index))
+        {
+            if let Some(destination) = read(env, &address) {
+                result.push_back(destination);
+            }
+        }
+        index += 1;
+    }
+    result
+}
@@ -0,0 +1,228 @@
+use soroban_sdk::{contractevent, Address, BytesN, String, Vec};
+
+use crate::{FeeSchedule, OracleConfig, OrderStatus, SpendingLimits};
+
+/// Published when `execute_transfer` pays an allowlisted destination.
+/// `amount` is what the destination received, net of any fee.
+#[contractevent(topics = ["transfer"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransferExecuted {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub to: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published by `execute_payment` when a transfer carries a reference,
+/// after the `transfer` event for the same payment. `amount` is gross;
+/// the destination received `net` after `fee`.
+#[contractevent(topics = ["payment"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentExecuted {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub reference: BytesN<32>,
+    pub to: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub net: i128,
+    pub memo: Option<String>,
+}
+
+/// Published once per `execute_batch_transfer`, after the per-line
+/// `transfer` events.
+#[contractevent(topics = ["batch_transfer"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchTransferExecuted {
+    #[topic]
+    pub label: String,
+    pub token: Address,
+    pub count: u32,
+    pub total: i128,
+}
+
+/// Published when the admin pulls funds out of the account.
+#[contractevent(topics = ["admin_withdraw"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminWithdrawal {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub to: Address,
+    pub token: Address,
+    pub amount: i128,
+}
+
+/// Published when `execute_forex_transfer` swaps and delivers the proceeds.
+/// `token` is the asset delivered to `to`, `amount` the input swapped after
+/// any fee, and `venue` the router or swap adapter that filled it.
+#[contractevent(topics = ["forex_transfer"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForexTransferExecuted {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub to: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub amount_out: i128,
+    pub path: Vec<Address>,
+    pub venue: Address,
+}
+
+/// Published when `swap_holdings` converts funds the account keeps.
+#[contractevent(topics = ["holdings_swapped"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HoldingsSwapped {
+    #[topic]
+    pub label: String,
+    pub token_in: Address,
+    pub token_out: Address,
+    pub amount_in: i128,
+    pub amount_out: i128,
+    pub venue: Address,
+}
+
+/// Published when the destination registry is replaced wholesale.
+#[contractevent(topics = ["destinations_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DestinationsUpdated {
+    #[topic]
+    pub label: String,
+    pub destinations: Vec<Address>,
+}
+
+/// Published when a destination is registered, edited, enabled or disabled.
+#[contractevent(topics = ["destination_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DestinationSet {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub address: Address,
+    pub corridor: String,
+    pub enabled: bool,
+}
+
+/// Published when a destination is removed from the registry.
+#[contractevent(topics = ["destination_removed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DestinationRemoved {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub address: Address,
+}
+
+/// Published when the set of supported payout tokens changes.
+#[contractevent(topics = ["tokens_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SupportedTokensUpdated {
+    #[topic]
+    pub label: String,
+    pub tokens: Vec<Address>,
+}
+
+/// Published when the outbound limits for a token change.
+#[contractevent(topics = ["limits_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendingLimitsUpdated {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub token: Address,
+    pub limits: SpendingLimits,
+}
+
+/// Published when a corridor fee is sent to the fee collector. `to` is the
+/// destination of the payout the fee was charged on.
+#[contractevent(topics = ["fee_collected"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCollected {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub to: Address,
+    pub token: Address,
+    pub fee: i128,
+}
+
+/// Published when the fee collector changes.
+#[contractevent(topics = ["fee_collector_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeCollectorSet {
+    #[topic]
+    pub label: String,
+    pub collector: Address,
+}
+
+/// Published when a fee schedule is set or removed; `destination` is `None`
+/// for the token's default schedule.
+#[contractevent(topics = ["fee_schedule_set"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeScheduleSet {
+    #[topic]
+    pub label: String,
+    pub token: Address,
+    pub destination: Option<Address>,
+    pub schedule: Option<FeeSchedule>,
+}
+
+/// Published when a standing order is created or changes status.
+#[contractevent(topics = ["order_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StandingOrderUpdated {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub id: u32,
+    pub status: OrderStatus,
+}
+
+/// Published for every payment made by a standing order; `run` counts
+/// from 1.
+#[contractevent(topics = ["order_executed"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StandingOrderExecuted {
+    #[topic]
+    pub label: String,
+    #[topic]
+    pub id: u32,
+    pub to: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub run: u32,
+}
+
+/// Published when the approved forex routes or intermediate tokens change.
+#[contractevent(topics = ["forex_routing"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForexRoutingUpdated {
+    #[topic]
+    pub label: String,
+    pub routes: Vec<Vec<Address>>,
+    pub intermediates: Vec<Address>,
+}
+
+/// Published when the set of swap adapters changes.
+#[contractevent(topics = ["adapters_updated"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapAdaptersUpdated {
+    #[topic]
+    pub label: String,
+    pub adapters: Vec<Address>,
+}
+
+/// Published when the forex router and counter token are (re)configured.
+#[contractevent(topics = ["forex_configured"])]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForexConfigured {
+    #[topic]
+    pub label: String,
+    pub router: Address,
+    pub counter_token: Address,
+    pub oracle: Option<OracleConfig>,
+}
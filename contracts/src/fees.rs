@@ -0,0 +1,117 @@
+use soroban_sdk::{contracttype, Address, Env};
+
+use crate::{extend_persistent_ttl, DataKey, RemittanceError};
+
+pub const BPS_DENOMINATOR: u32 = 10_000;
+
+/// Fee charged on a payout: `bps` of the gross amount, but never less than
+/// `min_fee`. Schedules are set per token, so `min_fee` is in that token's
+/// base units.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSchedule {
+    pub bps: u32,
+    pub min_fee: i128,
+}
+
+impl FeeSchedule {
+    pub fn validate(&self) -> Result<(), RemittanceError> {
+        if self.bps > BPS_DENOMINATOR || self.min_fee < 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        Ok(())
+    }
+}
+
+pub fn read_collector(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::FeeCollector)
+}
+
+pub fn write_collector(env: &Env, collector: &Address) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeCollector, collector);
+}
+
+fn schedule_key(token: Address, destination: Option<Address>) -> DataKey {
+    match destination {
+        Some(to) => DataKey::DestinationFee(token, to),
+        None => DataKey::DefaultFee(token),
+    }
+}
+
+/// The schedule set for `destination` in `token`, or the token's default
+/// when `destination` is `None`.
+pub fn read_schedule(
+    env: &Env,
+    token: &Address,
+    destination: Option<Address>,
+) -> Option<FeeSchedule> {
+    let key = schedule_key(token.clone(), destination);
+    let schedule = env.storage().persistent().get::<_, FeeSchedule>(&key);
+    if schedule.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    schedule
+}
+
+pub fn write_schedule(
+    env: &Env,
+    token: &Address,
+    destination: Option<Address>,
+    schedule: Option<FeeSchedule>,
+) {
+    let key = schedule_key(token.clone(), destination);
+    match schedule {
+        Some(schedule) => {
+            env.storage().persistent().set(&key, &schedule);
+            extend_persistent_ttl(env, &key);
+        }
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Fee owed on paying `amount` of `token` to `to`: the destination's own
+/// schedule for that token if it has one, else the token's default. No fee
+/// is charged until a collector is configured, nor in a token without a
+/// schedule. Errors if the fee would leave nothing for the recipient.
+pub fn compute(
+    env: &Env,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<i128, RemittanceError> {
+    if read_collector(env).is_none() {
+        return Ok(0);
+    }
+    let schedule =
+        read_schedule(env, token, Some(to.clone())).or_else(|| read_schedule(env, token, None));
+    let Some(schedule) = schedule else {
+        return Ok(0);
+    };
+    let fee = amount
+        .checked_mul(schedule.bps as i128)
+        .ok_or(RemittanceError::InvalidAmount)?
+        / BPS_DENOMINATOR as i128;
+    let fee = fee.max(schedule.min_fee);
+    if fee >= amount {
+        return Err(RemittanceError::InvalidAmount);
+    }
+    Ok(fee)
+}
+
+pub fn collected(env: &Env, token: &Address) -> i128 {
+    let key = DataKey::FeesCollected(token.clone());
+    let total = env.storage().persistent().get::<_, i128>(&key);
+    if total.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    total.unwrap_or(0)
+}
+
+pub fn record(env: &Env, token: &Address, fee: i128) {
+    let key = DataKey::FeesCollected(token.clone());
+    let total = collected(env, token).saturating_add(fee);
+    env.storage().persistent().set(&key, &total);
+    extend_persistent_ttl(env, &key);
+}
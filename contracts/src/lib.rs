@@ -1,10 +1,13 @@
 #![no_std]
 
-use soroban_sdk::auth::{Context, CustomAccountInterface};
+use soroban_sdk::auth::{
+    Context, ContractContext, CustomAccountInterface, InvokerContractAuthEntry,
+    SubContractInvocation,
+};
 use soroban_sdk::crypto::Hash;
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, symbol_short, token, vec, Address, Env,
-    Map, String, Symbol, Val, Vec,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, token, vec,
+    Address, BytesN, Env, IntoVal, Map, String, Symbol, Val, Vec,
 };
 use stellar_accounts::smart_account::{
     add_context_rule, add_policy, add_signer, do_check_auth, get_context_rule, get_context_rules,
@@ -13,14 +16,43 @@ use stellar_accounts::smart_account::{
     SmartAccount, SmartAccountError,
 };
 
+mod adapters;
+mod destinations;
+mod events;
+mod fees;
+mod limits;
+mod oracle;
+mod orders;
+mod payments;
+mod routes;
 mod test;
 
+pub use destinations::Destination;
+pub use events::{
+    AdminWithdrawal, BatchTransferExecuted, DestinationRemoved, DestinationSet,
+    DestinationsUpdated, FeeCollected, FeeCollectorSet, FeeScheduleSet, ForexConfigured,
+    ForexRoutingUpdated, ForexTransferExecuted, HoldingsSwapped, PaymentExecuted,
+    SpendingLimitsUpdated, StandingOrderExecuted, StandingOrderUpdated, SupportedTokensUpdated,
+    SwapAdaptersUpdated, TransferExecuted,
+};
+pub use fees::FeeSchedule;
+pub use limits::{SpendEntry, SpendingLimits};
+pub use oracle::OracleConfig;
+pub use orders::{OrderStatus, StandingOrder};
+pub use payments::Payment;
+
 #[allow(dead_code)]
 mod soroswap_router {
     use super::*;
 
     #[contractclient(name = "SoroswapRouterClient")]
     pub trait SoroswapRouterContract {
+        fn router_get_amounts_out(env: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128>;
+
+        fn router_get_amounts_in(env: Env, amount_out: i128, path: Vec<Address>) -> Vec<i128>;
+
+        fn router_pair_for(env: Env, token_a: Address, token_b: Address) -> Address;
+
         fn swap_exact_tokens_for_tokens(
             env: Env,
             amount_in: i128,
@@ -29,6 +61,15 @@ mod soroswap_router {
             to: Address,
             deadline: u64,
         ) -> Vec<i128>;
+
+        fn swap_tokens_for_exact_tokens(
+            env: Env,
+            amount_out: i128,
+            amount_in_max: i128,
+            path: Vec<Address>,
+            to: Address,
+            deadline: u64,
+        ) -> Vec<i128>;
     }
 }
 
@@ -40,7 +81,13 @@ mod multisig_treasury {
 
     #[contractclient(name = "MultisigTreasuryClient")]
     pub trait MultisigTreasuryContract {
-        fn propose_withdraw(env: Env, signer: Address, to: Address, amount: i128) -> u32;
+        fn propose_withdraw(
+            env: Env,
+            signer: Address,
+            token: Address,
+            to: Address,
+            amount: i128,
+        ) -> u32;
         fn approve_withdraw(env: Env, signer: Address, request_id: u32) -> bool;
     }
 }
@@ -49,10 +96,69 @@ use multisig_treasury::MultisigTreasuryClient;
 
 const ADMIN_KEY: Symbol = symbol_short!("admin");
 const TOKEN_KEY: Symbol = symbol_short!("token");
-const DEST_KEY: Symbol = symbol_short!("dest");
 const LABEL_KEY: Symbol = symbol_short!("label");
 const ROUTER_KEY: Symbol = symbol_short!("router");
 const FOREX_TOKEN_KEY: Symbol = symbol_short!("fx_tok");
+const TOKENS_KEY: Symbol = symbol_short!("tokens");
+
+const DAY_IN_LEDGERS: u32 = 17_280;
+const PERSISTENT_TTL_EXTEND: u32 = 30 * DAY_IN_LEDGERS;
+const PERSISTENT_TTL_THRESHOLD: u32 = PERSISTENT_TTL_EXTEND - DAY_IN_LEDGERS;
+
+/// Most lines accepted by a single `execute_batch_transfer` call.
+const MAX_BATCH_SIZE: u32 = 50;
+
+#[derive(Clone)]
+#[contracttype]
+pub enum DataKey {
+    DestinationCount,
+    Destination(Address),
+    DestinationIndex(Address),
+    DestinationAt(u32),
+    SpendingLimits(Address),
+    AccountSpend(Address),
+    DestinationSpend(Address, Address),
+    NextOrderId,
+    OpenOrders,
+    Order(u32),
+    Payment(BytesN<32>),
+    FeeCollector,
+    DefaultFee(Address),
+    DestinationFee(Address, Address),
+    FeesCollected(Address),
+    ForexRoutes,
+    ForexIntermediates,
+    Oracle,
+    SwapAdapters,
+}
+
+/// Outcome of one line of `execute_batch_transfer`, in input order.
+/// `amount` is the requested line amount, of which `fee` went to the fee
+/// collector and `net` to `to`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchTransferResult {
+    pub index: u32,
+    pub to: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub net: i128,
+}
+
+/// Preview of a forex transfer. `amount_in` is everything the account
+/// would spend, `fee` the part of it going to the fee collector and
+/// `amount_out` what `to` would receive. `min_amount_out` is the smallest
+/// minimum the contract would accept for the swap, from the oracle floor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForexQuote {
+    pub amount_in: i128,
+    pub fee: i128,
+    pub amount_out: i128,
+    pub min_amount_out: Option<i128>,
+    pub path: Vec<Address>,
+    pub venue: Address,
+}
 
 #[contracterror]
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
@@ -62,11 +168,33 @@ pub enum RemittanceError {
     ForexNotConfigured = 3,
     InvalidAmount = 4,
     SwapFailed = 5,
+    // 6 is retired: setup runs once, in `__constructor`
+    TokenNotSupported = 7,
+    DestinationNotFound = 8,
+    LimitExceeded = 9,
+    OrderNotFound = 10,
+    InvalidSchedule = 11,
+    InvalidBatch = 12,
+    InsufficientBalance = 13,
+    DuplicateReference = 14,
+    PaymentNotFound = 15,
+    MemoTooLong = 16,
+    InvalidPath = 17,
+    SlippageTooHigh = 18,
+    OracleUnavailable = 19,
+    OracleStale = 20,
+    MemoWithoutReference = 21,
 }
 
 #[contract]
 pub struct RemittanceAccount;
 
+fn extend_persistent_ttl(env: &Env, key: &DataKey) {
+    env.storage()
+        .persistent()
+        .extend_ttl(key, PERSISTENT_TTL_THRESHOLD, PERSISTENT_TTL_EXTEND);
+}
+
 fn read_admin(env: &Env) -> Result<Address, RemittanceError> {
     env.storage()
         .instance()
@@ -81,11 +209,38 @@ fn read_token(env: &Env) -> Result<Address, RemittanceError> {
         .ok_or(RemittanceError::NotInitialized)
 }
 
-fn read_destinations(env: &Env) -> Result<Vec<Address>, RemittanceError> {
+/// Tokens the account may pay out. Accounts initialized before the list
+/// existed fall back to their primary token only.
+fn read_supported_tokens(env: &Env) -> Result<Vec<Address>, RemittanceError> {
+    match env.storage().instance().get::<_, Vec<Address>>(&TOKENS_KEY) {
+        Some(tokens) => Ok(tokens),
+        None => Ok(vec![env, read_token(env)?]),
+    }
+}
+
+fn write_supported_tokens(env: &Env, tokens: &Vec<Address>) {
+    env.storage().instance().set(&TOKENS_KEY, tokens);
+    SupportedTokensUpdated {
+        label: read_label(env),
+        tokens: tokens.clone(),
+    }
+    .publish(env);
+}
+
+fn ensure_token_supported(env: &Env, token: &Address) -> Result<(), RemittanceError> {
+    let supported = read_supported_tokens(env)?;
+    if supported.iter().any(|addr| addr == *token) {
+        Ok(())
+    } else {
+        Err(RemittanceError::TokenNotSupported)
+    }
+}
+
+fn read_label(env: &Env) -> String {
     env.storage()
         .instance()
-        .get::<_, Vec<Address>>(&DEST_KEY)
-        .ok_or(RemittanceError::NotInitialized)
+        .get::<_, String>(&LABEL_KEY)
+        .unwrap_or(String::from_str(env, ""))
 }
 
 fn read_router(env: &Env) -> Result<Address, RemittanceError> {
@@ -102,29 +257,391 @@ fn read_forex_token(env: &Env) -> Result<Address, RemittanceError> {
         .ok_or(RemittanceError::ForexNotConfigured)
 }
 
-fn ensure_destination_allowed(env: &Env, to: &Address) -> Result<(), RemittanceError> {
-    let allowed = read_destinations(env)?;
-    if allowed.iter().any(|addr| addr == *to) {
-        Ok(())
+/// Tokens a forex path may start or end in: every supported payout token
+/// plus the forex counter token.
+fn read_forex_endpoints(env: &Env) -> Result<Vec<Address>, RemittanceError> {
+    let mut endpoints = read_supported_tokens(env)?;
+    let counter_token = read_forex_token(env)?;
+    if !endpoints.contains(&counter_token) {
+        endpoints.push_back(counter_token);
+    }
+    Ok(endpoints)
+}
+
+fn publish_forex_routing(env: &Env) {
+    ForexRoutingUpdated {
+        label: read_label(env),
+        routes: routes::read_routes(env),
+        intermediates: routes::read_intermediates(env),
+    }
+    .publish(env);
+}
+
+/// Swap `amount` of `path[0]` along `path` and pay the proceeds, in the last
+/// token of the path, to `to`. Authorization, the destination check and
+/// path validation are left to the caller.
+fn swap_and_deliver(
+    env: &Env,
+    to: Address,
+    amount: i128,
+    min_amount_out: i128,
+    deadline: u64,
+    path: Vec<Address>,
+) -> Result<(), RemittanceError> {
+    let input_token = path.first().ok_or(RemittanceError::InvalidPath)?;
+    let output_token = path.last().ok_or(RemittanceError::InvalidPath)?;
+    limits::enforce(env, &input_token, &to, amount)?;
+
+    // The corridor fee is taken from the input before swapping
+    let fee = fees::compute(env, &input_token, &to, amount)?;
+    collect_fee(env, &input_token, &to, fee);
+    let amount = amount - fee;
+
+    // Never accept less than the oracle-implied floor, whatever the caller asked
+    if let Some(floor) = oracle::min_amount_out(env, &input_token, &output_token, amount)? {
+        if min_amount_out < floor {
+            return Err(RemittanceError::SlippageTooHigh);
+        }
+    }
+
+    let (venue, amount_out) = swap(env, amount, min_amount_out, &path, deadline)?;
+
+    let self_address = env.current_contract_address();
+    let token_client = token::Client::new(env, &output_token);
+    token_client.transfer(&self_address, &to, &amount_out);
+    ForexTransferExecuted {
+        label: read_label(env),
+        to,
+        token: output_token,
+        amount,
+        amount_out,
+        path,
+        venue,
+    }
+    .publish(env);
+    Ok(())
+}
+
+/// Authorize the router to move `amount_in` of `path[0]` from the account
+/// into the first pool of `path`, which it does on our behalf mid-swap.
+fn authorize_router_pull(
+    env: &Env,
+    router: &SoroswapRouterClient,
+    path: &Vec<Address>,
+    amount_in: i128,
+) -> Result<(), RemittanceError> {
+    let input_token = path.get(0).ok_or(RemittanceError::InvalidPath)?;
+    let next_token = path.get(1).ok_or(RemittanceError::InvalidPath)?;
+    let pair = router.router_pair_for(&input_token, &next_token);
+    env.authorize_as_current_contract(vec![
+        env,
+        InvokerContractAuthEntry::Contract(SubContractInvocation {
+            context: ContractContext {
+                contract: input_token,
+                fn_name: symbol_short!("transfer"),
+                args: (env.current_contract_address(), pair, amount_in).into_val(env),
+            },
+            sub_invocations: vec![env],
+        }),
+    ]);
+    Ok(())
+}
+
+/// Swap `amount` of `path[0]` into the last path token, held by the account.
+/// With swap adapters registered the swap goes through whichever quotes the
+/// most output; otherwise it goes straight to the configured router.
+/// Returns the venue used and the output received, measured from the
+/// account's balance rather than taken from the venue's word.
+fn swap(
+    env: &Env,
+    amount: i128,
+    min_amount_out: i128,
+    path: &Vec<Address>,
+    deadline: u64,
+) -> Result<(Address, i128), RemittanceError> {
+    let self_address = env.current_contract_address();
+    let input_token = path.first().ok_or(RemittanceError::InvalidPath)?;
+    let output_token = path.last().ok_or(RemittanceError::InvalidPath)?;
+    let output_client = token::Client::new(env, &output_token);
+    let balance_before = output_client.balance(&self_address);
+
+    let (venue, reported) = if adapters::read(env).is_empty() {
+        let router = read_router(env)?;
+        let router_client = SoroswapRouterClient::new(env, &router);
+        authorize_router_pull(env, &router_client, path, amount)?;
+        let swap_amounts = router_client.swap_exact_tokens_for_tokens(
+            &amount,
+            &min_amount_out,
+            path,
+            &self_address,
+            &deadline,
+        );
+        (router, last_amount(&swap_amounts)?)
+    } else {
+        let (adapter, _) =
+            adapters::best_quote(env, amount, path).ok_or(RemittanceError::SwapFailed)?;
+        token::Client::new(env, &input_token).transfer(&self_address, &adapter, &amount);
+        let reported = adapters::SwapAdapterClient::new(env, &adapter).swap(
+            &amount,
+            &min_amount_out,
+            path,
+            &self_address,
+            &deadline,
+        );
+        (adapter, reported)
+    };
+
+    let received = output_client.balance(&self_address) - balance_before;
+    if received < min_amount_out || received != reported {
+        return Err(RemittanceError::SwapFailed);
+    }
+    Ok((venue, received))
+}
+
+/// Swap as little of `path[0]` as needed, up to `max_amount_in`, for exactly
+/// `amount_out` of the last path token and pay it to `to`. Always uses the
+/// configured router, since swap adapters only quote exact inputs. The fee
+/// is charged on top of the input spent. Returns the input spent on the
+/// swap; the rest of `max_amount_in` never leaves the account.
+fn swap_exact_out_and_deliver(
+    env: &Env,
+    to: Address,
+    amount_out: i128,
+    max_amount_in: i128,
+    deadline: u64,
+    path: Vec<Address>,
+) -> Result<i128, RemittanceError> {
+    let router = read_router(env)?;
+    let input_token = path.first().ok_or(RemittanceError::InvalidPath)?;
+    let output_token = path.last().ok_or(RemittanceError::InvalidPath)?;
+
+    // The worst rate the caller accepts must respect the oracle floor
+    if let Some(floor) = oracle::min_amount_out(env, &input_token, &output_token, max_amount_in)? {
+        if amount_out < floor {
+            return Err(RemittanceError::SlippageTooHigh);
+        }
+    }
+
+    let router_client = SoroswapRouterClient::new(env, &router);
+    let self_address = env.current_contract_address();
+    let input_client = token::Client::new(env, &input_token);
+    let output_client = token::Client::new(env, &output_token);
+    let input_before = input_client.balance(&self_address);
+    let output_before = output_client.balance(&self_address);
+    let expected_in = router_client.router_get_amounts_in(&amount_out, &path);
+    let expected_in = expected_in.first().ok_or(RemittanceError::SwapFailed)?;
+    authorize_router_pull(env, &router_client, &path, expected_in)?;
+    let swap_amounts = router_client.swap_tokens_for_exact_tokens(
+        &amount_out,
+        &max_amount_in,
+        &path,
+        &self_address,
+        &deadline,
+    );
+    if swap_amounts.len() < 2 {
+        return Err(RemittanceError::SwapFailed);
+    }
+
+    // Check both legs against the balances, not the router's report
+    let amount_in = input_before - input_client.balance(&self_address);
+    let received = output_client.balance(&self_address) - output_before;
+    let reported_in = swap_amounts.first().ok_or(RemittanceError::SwapFailed)?;
+    let reported_out = swap_amounts.last().ok_or(RemittanceError::SwapFailed)?;
+    if amount_in != reported_in || received != reported_out {
+        return Err(RemittanceError::SwapFailed);
+    }
+    if amount_in > max_amount_in || received < amount_out {
+        return Err(RemittanceError::SwapFailed);
+    }
+
+    // The fee comes on top of the swap input, so the ceiling covers both.
+    // Limits cover everything that left the account; failing here undoes the swap
+    let fee = fees::compute(env, &input_token, &to, amount_in)?;
+    if amount_in.saturating_add(fee) > max_amount_in {
+        return Err(RemittanceError::SlippageTooHigh);
+    }
+    limits::enforce(env, &input_token, &to, amount_in + fee)?;
+    collect_fee(env, &input_token, &to, fee);
+
+    token::Client::new(env, &output_token).transfer(&self_address, &to, &amount_out);
+    ForexTransferExecuted {
+        label: read_label(env),
+        to,
+        token: output_token,
+        amount: amount_in,
+        amount_out,
+        path,
+        venue: router,
+    }
+    .publish(env);
+    Ok(amount_in)
+}
+
+/// The direct path between the primary and counter tokens.
+fn forex_path(env: &Env, swap_to_counter: bool) -> Result<Vec<Address>, RemittanceError> {
+    let counter_token = read_forex_token(env)?;
+    let primary_token = read_token(env)?;
+    Ok(if swap_to_counter {
+        vec![env, primary_token, counter_token]
     } else {
-        Err(RemittanceError::NotAllowed)
+        vec![env, counter_token, primary_token]
+    })
+}
+
+fn last_amount(amounts: &Vec<i128>) -> Result<i128, RemittanceError> {
+    if amounts.len() < 2 {
+        return Err(RemittanceError::SwapFailed);
+    }
+    amounts.last().ok_or(RemittanceError::SwapFailed)
+}
+
+fn ensure_destination_allowed(env: &Env, to: &Address) -> Result<(), RemittanceError> {
+    match destinations::read(env, to) {
+        Some(destination) if destination.enabled => Ok(()),
+        _ => Err(RemittanceError::NotAllowed),
+    }
+}
+
+fn write_destination(env: &Env, destination: &Destination) {
+    destinations::write(env, destination);
+    DestinationSet {
+        label: read_label(env),
+        address: destination.address.clone(),
+        corridor: destination.corridor.clone(),
+        enabled: destination.enabled,
+    }
+    .publish(env);
+}
+
+fn transfer_to_destination(
+    env: &Env,
+    token: Address,
+    to: Address,
+    amount: i128,
+) -> Result<i128, RemittanceError> {
+    env.current_contract_address().require_auth();
+    pay_destination(env, token, to, amount)
+}
+
+/// Pay an allowlisted destination without checking authorization; callers
+/// are responsible for that. Returns the fee taken out of `amount`.
+///
+/// Every fallible check runs before the spending limits record anything,
+/// so a payout that fails leaves no trace even when the caller carries on,
+/// as `execute_due_orders` does.
+fn pay_destination(
+    env: &Env,
+    token: Address,
+    to: Address,
+    amount: i128,
+) -> Result<i128, RemittanceError> {
+    ensure_destination_allowed(env, &to)?;
+    ensure_token_supported(env, &token)?;
+    let fee = fees::compute(env, &token, &to, amount)?;
+    limits::enforce(env, &token, &to, amount)?;
+    let net = amount - fee;
+    let self_address = env.current_contract_address();
+    let client = token::Client::new(env, &token);
+    client.transfer(&self_address, &to, &net);
+    collect_fee(env, &token, &to, fee);
+    TransferExecuted {
+        label: read_label(env),
+        to,
+        token,
+        amount: net,
+    }
+    .publish(env);
+    Ok(fee)
+}
+
+/// Send `fee` of `token`, charged on a payout to `to`, to the fee collector.
+fn collect_fee(env: &Env, token: &Address, to: &Address, fee: i128) {
+    if fee == 0 {
+        return;
     }
+    let Some(collector) = fees::read_collector(env) else {
+        return;
+    };
+    token::Client::new(env, token).transfer(&env.current_contract_address(), &collector, &fee);
+    fees::record(env, token, fee);
+    FeeCollected {
+        label: read_label(env),
+        to: to.clone(),
+        token: token.clone(),
+        fee,
+    }
+    .publish(env);
+}
+
+fn write_order_status(env: &Env, order: &mut StandingOrder, status: OrderStatus) {
+    order.status = status;
+    if matches!(status, OrderStatus::Cancelled | OrderStatus::Completed) {
+        orders::close(env, order.id);
+    }
+    orders::write(env, order);
+    StandingOrderUpdated {
+        label: read_label(env),
+        id: order.id,
+        status,
+    }
+    .publish(env);
+}
+
+/// Pay one run of a standing order. Returns an error instead of trapping
+/// when the account cannot cover it, so one failing order does not block
+/// the others.
+fn pay_standing_order(env: &Env, order: &StandingOrder) -> Result<(), RemittanceError> {
+    let client = token::Client::new(env, &order.token);
+    if client.balance(&env.current_contract_address()) < order.amount {
+        return Err(RemittanceError::InsufficientBalance);
+    }
+    pay_destination(
+        env,
+        order.token.clone(),
+        order.destination.clone(),
+        order.amount,
+    )?;
+    Ok(())
+}
+
+fn withdraw_to_admin(env: &Env, token: Address, amount: i128) -> Result<(), RemittanceError> {
+    let admin = read_admin(env)?;
+    admin.require_auth();
+    ensure_token_supported(env, &token)?;
+    limits::enforce(env, &token, &admin, amount)?;
+    let self_address = env.current_contract_address();
+    let client = token::Client::new(env, &token);
+    client.transfer(&self_address, &admin, &amount);
+    AdminWithdrawal {
+        label: read_label(env),
+        to: admin,
+        token,
+        amount,
+    }
+    .publish(env);
+    Ok(())
 }
 
 #[contractimpl]
 impl RemittanceAccount {
-    pub fn init(
+    /// Runs once, atomically with deployment, so nobody can initialize the
+    /// account ahead of its deployer.
+    pub fn __constructor(
         env: Env,
         admin: Address,
         token: Address,
         destinations: Vec<Address>,
         label: String,
     ) {
-        admin.require_auth();
         env.storage().instance().set(&ADMIN_KEY, &admin);
         env.storage().instance().set(&TOKEN_KEY, &token);
-        env.storage().instance().set(&DEST_KEY, &destinations);
+        env.storage()
+            .instance()
+            .set(&TOKENS_KEY, &vec![&env, token.clone()]);
         env.storage().instance().set(&LABEL_KEY, &label);
+        for address in destinations.iter() {
+            destinations::write(&env, &Destination::new(&env, address));
+        }
         let signers = vec![&env, Signer::Delegated(admin)];
         let policies: Map<Address, Val> = Map::new(&env);
         add_context_rule(
@@ -138,59 +655,576 @@ impl RemittanceAccount {
     }
 
     /// Execute a transfer to one of the whitelisted destinations
+    ///
+    /// Requires the smart account's own authorization, so the context rules
+    /// installed at construction decide who may move funds.
     pub fn execute_transfer(env: Env, to: Address, amount: i128) -> Result<(), RemittanceError> {
-        let allowed = read_destinations(&env)?;
-        if !allowed.iter().any(|addr| addr == to) {
-            return Err(RemittanceError::NotAllowed);
-        }
         let token = read_token(&env)?;
-        let self_address = env.current_contract_address();
-        let client = token::Client::new(&env, &token);
-        client.transfer(&self_address, &to, &amount);
+        transfer_to_destination(&env, token, to, amount)?;
+        Ok(())
+    }
+
+    /// Pay several whitelisted destinations in the primary token, all or
+    /// nothing. Every line and the batch total are validated before any
+    /// funds move.
+    pub fn execute_batch_transfer(
+        env: Env,
+        transfers: Vec<(Address, i128)>,
+    ) -> Result<Vec<BatchTransferResult>, RemittanceError> {
+        let token = read_token(&env)?;
+        env.current_contract_address().require_auth();
+        if transfers.is_empty() || transfers.len() > MAX_BATCH_SIZE {
+            return Err(RemittanceError::InvalidBatch);
+        }
+
+        let mut total: i128 = 0;
+        for (to, amount) in transfers.iter() {
+            if amount <= 0 {
+                return Err(RemittanceError::InvalidAmount);
+            }
+            ensure_destination_allowed(&env, &to)?;
+            total = total
+                .checked_add(amount)
+                .ok_or(RemittanceError::InvalidAmount)?;
+        }
+        let balance = token::Client::new(&env, &token).balance(&env.current_contract_address());
+        if total > balance {
+            return Err(RemittanceError::InsufficientBalance);
+        }
+
+        // A failing line returns an error, which rolls back the lines
+        // already paid.
+        let mut results = Vec::new(&env);
+        for (index, (to, amount)) in transfers.iter().enumerate() {
+            let fee = pay_destination(&env, token.clone(), to.clone(), amount)?;
+            results.push_back(BatchTransferResult {
+                index: index as u32,
+                to,
+                amount,
+                fee,
+                net: amount - fee,
+            });
+        }
+        BatchTransferExecuted {
+            label: read_label(&env),
+            token,
+            count: transfers.len(),
+            total,
+        }
+        .publish(&env);
+        Ok(results)
+    }
+
+    /// Execute a transfer in any supported token to a whitelisted destination,
+    /// optionally tagged with an idempotency `reference` and a `memo`. A
+    /// reference that was already executed is rejected until its record
+    /// expires, so a retried submission cannot pay twice. A memo is stored
+    /// with the reference, so it cannot be given without one.
+    pub fn execute_payment(
+        env: Env,
+        token: Address,
+        to: Address,
+        amount: i128,
+        reference: Option<BytesN<32>>,
+        memo: Option<String>,
+    ) -> Result<(), RemittanceError> {
+        if memo
+            .as_ref()
+            .is_some_and(|memo| memo.len() > payments::MAX_MEMO_LEN)
+        {
+            return Err(RemittanceError::MemoTooLong);
+        }
+        let Some(reference) = reference else {
+            if memo.is_some() {
+                return Err(RemittanceError::MemoWithoutReference);
+            }
+            transfer_to_destination(&env, token, to, amount)?;
+            return Ok(());
+        };
+        if payments::exists(&env, &reference) {
+            return Err(RemittanceError::DuplicateReference);
+        }
+        let fee = transfer_to_destination(&env, token.clone(), to.clone(), amount)?;
+        payments::write(
+            &env,
+            &Payment {
+                reference: reference.clone(),
+                to: to.clone(),
+                token,
+                amount,
+                fee,
+                net: amount - fee,
+                memo: memo.clone(),
+                timestamp: env.ledger().timestamp(),
+                ledger: env.ledger().sequence(),
+            },
+        );
+        PaymentExecuted {
+            label: read_label(&env),
+            reference,
+            to,
+            amount,
+            fee,
+            net: amount - fee,
+            memo,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Look up a payment made with `execute_payment` by its reference
+    pub fn get_payment(env: Env, reference: BytesN<32>) -> Result<Payment, RemittanceError> {
+        payments::read(&env, &reference).ok_or(RemittanceError::PaymentNotFound)
+    }
+
+    /// Execute a transfer in any supported token to a whitelisted destination
+    pub fn execute_token_transfer(
+        env: Env,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<(), RemittanceError> {
+        transfer_to_destination(&env, token, to, amount)?;
         Ok(())
     }
 
     /// Withdrawal to the admin address
     pub fn admin_withdraw(env: Env, amount: i128) -> Result<(), RemittanceError> {
+        let token = read_token(&env)?;
+        withdraw_to_admin(&env, token, amount)
+    }
+
+    /// Withdrawal of any supported token to the admin address
+    pub fn admin_withdraw_token(
+        env: Env,
+        token: Address,
+        amount: i128,
+    ) -> Result<(), RemittanceError> {
+        withdraw_to_admin(&env, token, amount)
+    }
+
+    /// Add a token to the set this account may pay out
+    pub fn add_supported_token(env: Env, token: Address) -> Result<(), RemittanceError> {
         let admin = read_admin(&env)?;
         admin.require_auth();
-        let token = read_token(&env)?;
-        let self_address = env.current_contract_address();
-        let client = token::Client::new(&env, &token);
-        client.transfer(&self_address, &admin, &amount);
+        let mut tokens = read_supported_tokens(&env)?;
+        if !tokens.iter().any(|addr| addr == token) {
+            tokens.push_back(token);
+            write_supported_tokens(&env, &tokens);
+        }
+        Ok(())
+    }
+
+    /// Remove a token from the supported set; the primary token always stays
+    pub fn remove_supported_token(env: Env, token: Address) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        if token == read_token(&env)? {
+            return Err(RemittanceError::NotAllowed);
+        }
+        let mut tokens = read_supported_tokens(&env)?;
+        let index = tokens
+            .first_index_of(&token)
+            .ok_or(RemittanceError::TokenNotSupported)?;
+        tokens.remove(index);
+        write_supported_tokens(&env, &tokens);
         Ok(())
     }
 
-    /// Update the allowed destinations
+    /// Get the tokens this account may pay out
+    pub fn get_supported_tokens(env: Env) -> Result<Vec<Address>, RemittanceError> {
+        read_supported_tokens(&env)
+    }
+
+    /// Replace the whole destination registry with bare entries for the
+    /// given addresses. Prefer the per-destination calls for large registries.
     pub fn update_destinations(
         env: Env,
         destinations: Vec<Address>,
     ) -> Result<(), RemittanceError> {
         let admin = read_admin(&env)?;
         admin.require_auth();
-        env.storage().instance().set(&DEST_KEY, &destinations);
+        destinations::clear(&env);
+        for address in destinations.iter() {
+            destinations::write(&env, &Destination::new(&env, address));
+        }
+        DestinationsUpdated {
+            label: read_label(&env),
+            destinations,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Set the outbound limits for a token. Caps on destinations and on the
+    /// account as a whole are measured over a rolling 24h window.
+    pub fn set_spending_limits(
+        env: Env,
+        token: Address,
+        limits: SpendingLimits,
+    ) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        let caps = [
+            limits.per_transaction,
+            limits.per_destination,
+            limits.per_account,
+        ];
+        if caps.iter().flatten().any(|cap| *cap < 0) {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        limits::write(&env, &token, &limits);
+        SpendingLimitsUpdated {
+            label: read_label(&env),
+            token,
+            limits,
+        }
+        .publish(&env);
         Ok(())
     }
 
+    /// Get the outbound limits configured for a token, if any
+    pub fn get_spending_limits(env: Env, token: Address) -> Option<SpendingLimits> {
+        limits::read(&env, &token)
+    }
+
+    /// Amount of a token spent in the current 24h window, account-wide or
+    /// towards one destination. Only dimensions with a cap are tracked
+    pub fn get_rolling_spend(env: Env, token: Address, destination: Option<Address>) -> i128 {
+        limits::spent(&env, &token, destination)
+    }
+
+    /// Schedule a recurring payment of `amount` every `interval` seconds,
+    /// first due at `start`, which may not be in the past
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_standing_order(
+        env: Env,
+        destination: Address,
+        token: Address,
+        amount: i128,
+        interval: u64,
+        start: u64,
+        end: Option<u64>,
+        max_runs: Option<u32>,
+    ) -> Result<u32, RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        if amount <= 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        if interval == 0
+            || start < env.ledger().timestamp()
+            || end.is_some_and(|end| end <= start)
+            || max_runs == Some(0)
+        {
+            return Err(RemittanceError::InvalidSchedule);
+        }
+        ensure_destination_allowed(&env, &destination)?;
+        ensure_token_supported(&env, &token)?;
+
+        let id = orders::next_id(&env);
+        orders::open(&env, id)?;
+        let mut order = StandingOrder {
+            id,
+            destination,
+            token,
+            amount,
+            interval,
+            next_run: start,
+            end,
+            max_runs,
+            runs: 0,
+            last_run: 0,
+            status: OrderStatus::Active,
+        };
+        write_order_status(&env, &mut order, OrderStatus::Active);
+        Ok(id)
+    }
+
+    /// Stop a standing order from paying until it is resumed
+    pub fn pause_standing_order(env: Env, id: u32) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        let mut order = orders::read(&env, id).ok_or(RemittanceError::OrderNotFound)?;
+        if order.status != OrderStatus::Active {
+            return Err(RemittanceError::NotAllowed);
+        }
+        write_order_status(&env, &mut order, OrderStatus::Paused);
+        Ok(())
+    }
+
+    /// Resume a paused standing order; runs missed while paused are skipped
+    pub fn resume_standing_order(env: Env, id: u32) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        let mut order = orders::read(&env, id).ok_or(RemittanceError::OrderNotFound)?;
+        if order.status != OrderStatus::Paused {
+            return Err(RemittanceError::NotAllowed);
+        }
+        let now = env.ledger().timestamp();
+        if order.next_run < now {
+            let missed = (now - order.next_run).div_ceil(order.interval);
+            order.next_run = order
+                .next_run
+                .saturating_add(missed.saturating_mul(order.interval));
+        }
+        write_order_status(&env, &mut order, OrderStatus::Active);
+        Ok(())
+    }
+
+    /// Cancel a standing order for good
+    pub fn cancel_standing_order(env: Env, id: u32) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        let mut order = orders::read(&env, id).ok_or(RemittanceError::OrderNotFound)?;
+        if matches!(
+            order.status,
+            OrderStatus::Cancelled | OrderStatus::Completed
+        ) {
+            return Err(RemittanceError::NotAllowed);
+        }
+        write_order_status(&env, &mut order, OrderStatus::Cancelled);
+        Ok(())
+    }
+
+    /// Get a standing order by id
+    pub fn get_standing_order(env: Env, id: u32) -> Result<StandingOrder, RemittanceError> {
+        orders::read(&env, id).ok_or(RemittanceError::OrderNotFound)
+    }
+
+    /// List standing orders that are active or paused
+    pub fn list_standing_orders(env: Env) -> Vec<StandingOrder> {
+        let mut result = Vec::new(&env);
+        for id in orders::open_ids(&env).iter() {
+            if let Some(order) = orders::read(&env, id) {
+                result.push_back(order);
+            }
+        }
+        result
+    }
+
+    /// Pay every active standing order that has fallen due. Anyone may call
+    /// this; orders were authorized by the admin when they were created.
+    /// Orders that cannot be paid right now (balance, limits, disabled
+    /// destination) are left due and retried on the next call. An order pays
+    /// at most once per call; runs that fell due while nobody called are
+    /// skipped, as on resume. Returns the ids that were paid.
+    pub fn execute_due_orders(env: Env) -> Vec<u32> {
+        let now = env.ledger().timestamp();
+        let mut paid = Vec::new(&env);
+        for id in orders::open_ids(&env).iter() {
+            let Some(mut order) = orders::read(&env, id) else {
+                continue;
+            };
+            if order.status != OrderStatus::Active || order.next_run > now {
+                continue;
+            }
+            if order.is_finished() {
+                write_order_status(&env, &mut order, OrderStatus::Completed);
+                continue;
+            }
+            if pay_standing_order(&env, &order).is_err() {
+                continue;
+            }
+            order.runs += 1;
+            order.last_run = now;
+            let missed = (now - order.next_run) / order.interval;
+            order.next_run = order
+                .next_run
+                .saturating_add(missed.saturating_add(1).saturating_mul(order.interval));
+            StandingOrderExecuted {
+                label: read_label(&env),
+                id,
+                to: order.destination.clone(),
+                token: order.token.clone(),
+                amount: order.amount,
+                run: order.runs,
+            }
+            .publish(&env);
+            if order.is_finished() {
+                write_order_status(&env, &mut order, OrderStatus::Completed);
+            } else {
+                orders::write(&env, &order);
+            }
+            paid.push_back(id);
+        }
+        paid
+    }
+
+    /// Set the address that receives corridor fees; no fees are charged
+    /// until one is set
+    pub fn set_fee_collector(env: Env, collector: Address) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        fees::write_collector(&env, &collector);
+        FeeCollectorSet {
+            label: read_label(&env),
+            collector,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_fee_collector(env: Env) -> Option<Address> {
+        fees::read_collector(&env)
+    }
+
+    /// Set the fee schedule for payouts in `token` to one destination, or
+    /// the token's default schedule when `destination` is `None`. Passing
+    /// `None` as the schedule removes it; a destination without its own
+    /// schedule pays the default, and a token without either pays no fee.
+    pub fn set_fee_schedule(
+        env: Env,
+        token: Address,
+        destination: Option<Address>,
+        schedule: Option<FeeSchedule>,
+    ) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        if let Some(schedule) = &schedule {
+            schedule.validate()?;
+        }
+        fees::write_schedule(&env, &token, destination.clone(), schedule.clone());
+        FeeScheduleSet {
+            label: read_label(&env),
+            token,
+            destination,
+            schedule,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_fee_schedule(
+        env: Env,
+        token: Address,
+        destination: Option<Address>,
+    ) -> Option<FeeSchedule> {
+        fees::read_schedule(&env, &token, destination)
+    }
+
+    /// Fee that a payout of `amount` of `token` to `to` would be charged
+    /// right now
+    pub fn quote_fee(
+        env: Env,
+        token: Address,
+        to: Address,
+        amount: i128,
+    ) -> Result<i128, RemittanceError> {
+        if amount <= 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        fees::compute(&env, &token, &to, amount)
+    }
+
+    /// Total fees collected in `token` since the account was created
+    pub fn get_fees_collected(env: Env, token: Address) -> i128 {
+        fees::collected(&env, &token)
+    }
+
+    /// Register a destination, or update the metadata of an existing one
+    /// without changing its enabled flag
+    pub fn add_destination(
+        env: Env,
+        address: Address,
+        label: String,
+        corridor: String,
+        preferred_token: Option<Address>,
+    ) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        if let Some(token) = &preferred_token {
+            ensure_token_supported(&env, token)?;
+        }
+        let enabled = destinations::read(&env, &address)
+            .map(|existing| existing.enabled)
+            .unwrap_or(true);
+        write_destination(
+            &env,
+            &Destination {
+                address,
+                label,
+                corridor,
+                preferred_token,
+                enabled,
+            },
+        );
+        Ok(())
+    }
+
+    /// Remove a destination from the registry
+    pub fn remove_destination(env: Env, address: Address) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        destinations::remove(&env, &address)?;
+        DestinationRemoved {
+            label: read_label(&env),
+            address,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    /// Enable or disable payments to a registered destination
+    pub fn set_destination_enabled(
+        env: Env,
+        address: Address,
+        enabled: bool,
+    ) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        let mut destination =
+            destinations::read(&env, &address).ok_or(RemittanceError::DestinationNotFound)?;
+        destination.enabled = enabled;
+        write_destination(&env, &destination);
+        Ok(())
+    }
+
+    /// Get a registered destination
+    pub fn get_destination(env: Env, address: Address) -> Result<Destination, RemittanceError> {
+        destinations::read(&env, &address).ok_or(RemittanceError::DestinationNotFound)
+    }
+
+    /// List registered destinations, at most 50 per page
+    pub fn list_destinations(env: Env, start: u32, limit: u32) -> Vec<Destination> {
+        destinations::page(&env, start, limit)
+    }
+
+    /// Number of registered destinations
+    pub fn destination_count(env: Env) -> u32 {
+        destinations::count(&env)
+    }
+
     /// Get the account label
     pub fn get_label(env: Env) -> String {
-        env.storage()
-            .instance()
-            .get::<_, String>(&LABEL_KEY)
-            .unwrap_or(String::from_str(&env, ""))
+        read_label(&env)
     }
 
+    /// Set the swap router and counter token, and optionally a SEP-40
+    /// price oracle that bounds the slippage every forex swap may accept
     pub fn configure_forex(
         env: Env,
         router: Address,
         counter_token: Address,
+        oracle: Option<OracleConfig>,
     ) -> Result<(), RemittanceError> {
         let admin = read_admin(&env)?;
         admin.require_auth();
+        if let Some(config) = &oracle {
+            config.validate()?;
+        }
         env.storage().instance().set(&ROUTER_KEY, &router);
         env.storage()
             .instance()
             .set(&FOREX_TOKEN_KEY, &counter_token);
+        oracle::write(&env, &oracle);
+        ForexConfigured {
+            label: read_label(&env),
+            router,
+            counter_token,
+            oracle,
+        }
+        .publish(&env);
         Ok(())
     }
 
@@ -205,69 +1239,302 @@ impl RemittanceAccount {
         if amount <= 0 || min_amount_out <= 0 {
             return Err(RemittanceError::InvalidAmount);
         }
+        env.current_contract_address().require_auth();
 
         ensure_destination_allowed(&env, &to)?;
 
-        let router = read_router(&env)?;
-        let counter_token = read_forex_token(&env)?;
-        let primary_token = read_token(&env)?;
-
-        let mut path = Vec::new(&env);
-        let output_token = if swap_to_counter {
-            path.push_back(primary_token.clone());
-            path.push_back(counter_token.clone());
-            counter_token
+        let path = forex_path(&env, swap_to_counter)?;
+        swap_and_deliver(&env, to, amount, min_amount_out, deadline, path)
+    }
+
+    /// Preview `execute_forex_transfer(to, amount, ..)`: the fee, the output
+    /// the router or best swap adapter quotes right now, and the oracle
+    /// floor. Fails with `SlippageTooHigh` if the quote is below the floor.
+    pub fn quote_forex(
+        env: Env,
+        to: Address,
+        amount: i128,
+        swap_to_counter: bool,
+    ) -> Result<ForexQuote, RemittanceError> {
+        if amount <= 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        let path = forex_path(&env, swap_to_counter)?;
+        let input_token = path.first().ok_or(RemittanceError::InvalidPath)?;
+        let output_token = path.last().ok_or(RemittanceError::InvalidPath)?;
+
+        let fee = fees::compute(&env, &input_token, &to, amount)?;
+        let swapped = amount - fee;
+        let (venue, amount_out) = if adapters::read(&env).is_empty() {
+            let router = read_router(&env)?;
+            let amounts =
+                SoroswapRouterClient::new(&env, &router).router_get_amounts_out(&swapped, &path);
+            (router, last_amount(&amounts)?)
         } else {
-            path.push_back(counter_token.clone());
-            path.push_back(primary_token.clone());
-            primary_token
+            adapters::best_quote(&env, swapped, &path).ok_or(RemittanceError::SwapFailed)?
         };
 
-        let router_client = SoroswapRouterClient::new(&env, &router);
-        let self_address = env.current_contract_address();
-        let swap_amounts = router_client.swap_exact_tokens_for_tokens(
-            &amount,
-            &min_amount_out,
-            &path,
-            &self_address,
-            &deadline,
-        );
+        let min_amount_out = oracle::min_amount_out(&env, &input_token, &output_token, swapped)?;
+        if min_amount_out.is_some_and(|floor| amount_out < floor) {
+            return Err(RemittanceError::SlippageTooHigh);
+        }
+        Ok(ForexQuote {
+            amount_in: amount,
+            fee,
+            amount_out,
+            min_amount_out,
+            path,
+            venue,
+        })
+    }
+
+    /// Preview `execute_forex_transfer_exact_out(to, amount_out, ..)`: the
+    /// input the router needs right now plus the fee charged on top of it.
+    /// Fails with `SlippageTooHigh` if the implied rate is below the oracle
+    /// floor.
+    pub fn quote_forex_exact_out(
+        env: Env,
+        to: Address,
+        amount_out: i128,
+        swap_to_counter: bool,
+    ) -> Result<ForexQuote, RemittanceError> {
+        if amount_out <= 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        let path = forex_path(&env, swap_to_counter)?;
+        let input_token = path.first().ok_or(RemittanceError::InvalidPath)?;
+        let output_token = path.last().ok_or(RemittanceError::InvalidPath)?;
 
-        let len = swap_amounts.len();
-        if len < 2 {
+        let router = read_router(&env)?;
+        let amounts =
+            SoroswapRouterClient::new(&env, &router).router_get_amounts_in(&amount_out, &path);
+        if amounts.len() < 2 {
             return Err(RemittanceError::SwapFailed);
         }
-        let last_index = len - 1;
-        let amount_out = swap_amounts
-            .get(last_index)
-            .ok_or(RemittanceError::SwapFailed)?;
+        let swapped = amounts.first().ok_or(RemittanceError::SwapFailed)?;
+        let fee = fees::compute(&env, &input_token, &to, swapped)?;
+
+        let min_amount_out = oracle::min_amount_out(&env, &input_token, &output_token, swapped)?;
+        if min_amount_out.is_some_and(|floor| amount_out < floor) {
+            return Err(RemittanceError::SlippageTooHigh);
+        }
+        Ok(ForexQuote {
+            amount_in: swapped + fee,
+            fee,
+            amount_out,
+            min_amount_out,
+            path,
+            venue: router,
+        })
+    }
+
+    /// Convert the account's own holdings between the primary and counter
+    /// tokens, keeping the proceeds. Admin only; subject to the same oracle
+    /// floor and router deadline as forex transfers, but not to fees or
+    /// spending limits since nothing leaves the account.
+    pub fn swap_holdings(
+        env: Env,
+        amount: i128,
+        min_amount_out: i128,
+        deadline: u64,
+        swap_to_counter: bool,
+    ) -> Result<i128, RemittanceError> {
+        if amount <= 0 || min_amount_out <= 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+
+        let path = forex_path(&env, swap_to_counter)?;
+        let input_token = path.first().ok_or(RemittanceError::InvalidPath)?;
+        let output_token = path.last().ok_or(RemittanceError::InvalidPath)?;
+        if let Some(floor) = oracle::min_amount_out(&env, &input_token, &output_token, amount)? {
+            if min_amount_out < floor {
+                return Err(RemittanceError::SlippageTooHigh);
+            }
+        }
+
+        let (venue, amount_out) = swap(&env, amount, min_amount_out, &path, deadline)?;
+        HoldingsSwapped {
+            label: read_label(&env),
+            token_in: input_token,
+            token_out: output_token,
+            amount_in: amount,
+            amount_out,
+            venue,
+        }
+        .publish(&env);
+        Ok(amount_out)
+    }
+
+    /// Forex transfer delivering exactly `amount_out` to `to`, spending at
+    /// most `max_amount_in` on the swap and its fee together. Returns the
+    /// input actually spent on the swap.
+    pub fn execute_forex_transfer_exact_out(
+        env: Env,
+        to: Address,
+        amount_out: i128,
+        max_amount_in: i128,
+        deadline: u64,
+        swap_to_counter: bool,
+    ) -> Result<i128, RemittanceError> {
+        if amount_out <= 0 || max_amount_in <= 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        env.current_contract_address().require_auth();
 
-        let token_client = token::Client::new(&env, &output_token);
-        token_client.transfer(&self_address, &to, &amount_out);
+        ensure_destination_allowed(&env, &to)?;
+
+        let path = forex_path(&env, swap_to_counter)?;
+        swap_exact_out_and_deliver(&env, to, amount_out, max_amount_in, deadline, path)
+    }
+
+    /// Forex transfer along a caller-supplied `path`, spending `path[0]`
+    /// and delivering the last token. The path must be an admin-approved
+    /// route or hop only through allowed intermediate tokens.
+    pub fn execute_forex_path_transfer(
+        env: Env,
+        to: Address,
+        amount: i128,
+        min_amount_out: i128,
+        deadline: u64,
+        path: Vec<Address>,
+    ) -> Result<(), RemittanceError> {
+        if amount <= 0 || min_amount_out <= 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        env.current_contract_address().require_auth();
+
+        ensure_destination_allowed(&env, &to)?;
+        routes::validate(&path, &read_forex_endpoints(&env)?)?;
+        routes::ensure_allowed(&env, &path)?;
+        swap_and_deliver(&env, to, amount, min_amount_out, deadline, path)
+    }
+
+    /// Approve a complete swap path, including its intermediate tokens
+    pub fn add_forex_route(env: Env, path: Vec<Address>) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        routes::validate(&path, &read_forex_endpoints(&env)?)?;
+        let mut approved = routes::read_routes(&env);
+        if approved.contains(&path) {
+            return Ok(());
+        }
+        if approved.len() >= routes::MAX_ROUTES {
+            return Err(RemittanceError::LimitExceeded);
+        }
+        approved.push_back(path);
+        routes::write_routes(&env, &approved);
+        publish_forex_routing(&env);
         Ok(())
     }
 
+    pub fn remove_forex_route(env: Env, path: Vec<Address>) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        let mut approved = routes::read_routes(&env);
+        let index = approved
+            .first_index_of(&path)
+            .ok_or(RemittanceError::InvalidPath)?;
+        approved.remove(index);
+        routes::write_routes(&env, &approved);
+        publish_forex_routing(&env);
+        Ok(())
+    }
+
+    pub fn get_forex_routes(env: Env) -> Vec<Vec<Address>> {
+        routes::read_routes(&env)
+    }
+
+    /// Replace the set of tokens caller-supplied paths may hop through
+    pub fn set_forex_intermediates(env: Env, tokens: Vec<Address>) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        routes::write_intermediates(&env, &tokens);
+        publish_forex_routing(&env);
+        Ok(())
+    }
+
+    /// Register a swap adapter; forex swaps then go through the registered
+    /// adapter quoting the best output instead of the router
+    pub fn add_swap_adapter(env: Env, adapter: Address) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        let mut registered = adapters::read(&env);
+        if registered.contains(&adapter) {
+            return Ok(());
+        }
+        if registered.len() >= adapters::MAX_ADAPTERS {
+            return Err(RemittanceError::LimitExceeded);
+        }
+        registered.push_back(adapter);
+        adapters::write(&env, &registered);
+        SwapAdaptersUpdated {
+            label: read_label(&env),
+            adapters: registered,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn remove_swap_adapter(env: Env, adapter: Address) -> Result<(), RemittanceError> {
+        let admin = read_admin(&env)?;
+        admin.require_auth();
+        let mut registered = adapters::read(&env);
+        let index = registered
+            .first_index_of(&adapter)
+            .ok_or(RemittanceError::NotAllowed)?;
+        registered.remove(index);
+        adapters::write(&env, &registered);
+        SwapAdaptersUpdated {
+            label: read_label(&env),
+            adapters: registered,
+        }
+        .publish(&env);
+        Ok(())
+    }
+
+    pub fn get_swap_adapters(env: Env) -> Vec<Address> {
+        adapters::read(&env)
+    }
+
+    pub fn get_oracle_config(env: Env) -> Option<OracleConfig> {
+        oracle::read(&env)
+    }
+
+    pub fn get_forex_intermediates(env: Env) -> Vec<Address> {
+        routes::read_intermediates(&env)
+    }
+
+    /// Propose a treasury withdrawal with this account as the signer.
+    /// Requires the account's own authorization: the treasury sees the
+    /// account as its direct invoker and would accept the vote unchecked.
     pub fn initiate_multisig_withdraw(
         env: Env,
         multisig: Address,
+        token: Address,
         to: Address,
         amount: i128,
     ) -> Result<(), RemittanceError> {
         if amount <= 0 {
             return Err(RemittanceError::InvalidAmount);
         }
+        env.current_contract_address().require_auth();
         ensure_destination_allowed(&env, &multisig)?;
         let client = MultisigTreasuryClient::new(&env, &multisig);
         let signer = env.current_contract_address();
-        client.propose_withdraw(&signer, &to, &amount);
+        client.propose_withdraw(&signer, &token, &to, &amount);
         Ok(())
     }
 
+    /// Approve a treasury withdrawal with this account as the signer;
+    /// authorized like `initiate_multisig_withdraw`.
     pub fn approve_multisig_withdraw(
         env: Env,
         multisig: Address,
         request_id: u32,
     ) -> Result<(), RemittanceError> {
+        env.current_contract_address().require_auth();
         ensure_destination_allowed(&env, &multisig)?;
         let client = MultisigTreasuryClient::new(&env, &multisig);
         let signer = env.current_contract_address();
@@ -0,0 +1,155 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{extend_persistent_ttl, DataKey, RemittanceError};
+
+/// Length of the rolling window the per-destination and per-account caps
+/// are measured over.
+pub const SPEND_WINDOW_SECONDS: u64 = 24 * 60 * 60;
+
+/// Spend is recorded in buckets of this many seconds, so a history never
+/// holds more than `SPEND_WINDOW_SECONDS / SPEND_BUCKET_SECONDS + 1`
+/// entries however busy the corridor is.
+pub const SPEND_BUCKET_SECONDS: u64 = 60 * 60;
+
+/// Outbound limits for one token, in that token's base units. `None`
+/// leaves the corresponding dimension unlimited.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendingLimits {
+    pub per_transaction: Option<i128>,
+    pub per_destination: Option<i128>,
+    pub per_account: Option<i128>,
+}
+
+/// Total spent in the bucket starting at `timestamp`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SpendEntry {
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn read(env: &Env, token: &Address) -> Option<SpendingLimits> {
+    let key = DataKey::SpendingLimits(token.clone());
+    let limits = env.storage().persistent().get::<_, SpendingLimits>(&key);
+    if limits.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    limits
+}
+
+pub fn write(env: &Env, token: &Address, limits: &SpendingLimits) {
+    let key = DataKey::SpendingLimits(token.clone());
+    env.storage().persistent().set(&key, limits);
+    extend_persistent_ttl(env, &key);
+}
+
+/// Buckets still inside the rolling window, oldest first. A bucket stays
+/// until its last second has left the window, so spend rolls off between
+/// 24 and 25 hours after it happened, never early.
+fn read_history(env: &Env, key: &DataKey) -> Vec<SpendEntry> {
+    let now = env.ledger().timestamp();
+    let history = env
+        .storage()
+        .persistent()
+        .get::<_, Vec<SpendEntry>>(key)
+        .unwrap_or(Vec::new(env));
+    let mut live = Vec::new(env);
+    for entry in history.iter() {
+        let expires = entry
+            .timestamp
+            .saturating_add(SPEND_BUCKET_SECONDS)
+            .saturating_add(SPEND_WINDOW_SECONDS);
+        if expires > now {
+            live.push_back(entry);
+        }
+    }
+    live
+}
+
+fn total(history: &Vec<SpendEntry>) -> i128 {
+    history
+        .iter()
+        .fold(0i128, |sum, entry| sum.saturating_add(entry.amount))
+}
+
+/// History for `key` with `amount` added to the current bucket, or an
+/// error if that would go over `cap`.
+fn check_and_record(
+    env: &Env,
+    key: &DataKey,
+    cap: i128,
+    amount: i128,
+) -> Result<Vec<SpendEntry>, RemittanceError> {
+    let mut history = read_history(env, key);
+    if total(&history).saturating_add(amount) > cap {
+        return Err(RemittanceError::LimitExceeded);
+    }
+    let now = env.ledger().timestamp();
+    let bucket = now - now % SPEND_BUCKET_SECONDS;
+    match history.last() {
+        Some(mut entry) if entry.timestamp == bucket => {
+            entry.amount = entry.amount.saturating_add(amount);
+            history.set(history.len() - 1, entry);
+        }
+        _ => history.push_back(SpendEntry {
+            amount,
+            timestamp: bucket,
+        }),
+    }
+    Ok(history)
+}
+
+fn write_history(env: &Env, key: &DataKey, history: &Vec<SpendEntry>) {
+    env.storage().persistent().set(key, history);
+    extend_persistent_ttl(env, key);
+}
+
+/// Check `amount` of `token` leaving the account for `to` against the
+/// configured limits and record it in the rolling windows. Only capped
+/// dimensions are tracked, and nothing is written unless every check
+/// passes.
+pub fn enforce(
+    env: &Env,
+    token: &Address,
+    to: &Address,
+    amount: i128,
+) -> Result<(), RemittanceError> {
+    let Some(limits) = read(env, token) else {
+        return Ok(());
+    };
+    if let Some(max) = limits.per_transaction {
+        if amount > max {
+            return Err(RemittanceError::LimitExceeded);
+        }
+    }
+
+    let account_key = DataKey::AccountSpend(token.clone());
+    let destination_key = DataKey::DestinationSpend(token.clone(), to.clone());
+    let account_history = match limits.per_account {
+        Some(cap) => Some(check_and_record(env, &account_key, cap, amount)?),
+        None => None,
+    };
+    let destination_history = match limits.per_destination {
+        Some(cap) => Some(check_and_record(env, &destination_key, cap, amount)?),
+        None => None,
+    };
+
+    if let Some(history) = account_history {
+        write_history(env, &account_key, &history);
+    }
+    if let Some(history) = destination_history {
+        write_history(env, &destination_key, &history);
+    }
+    Ok(())
+}
+
+/// Amount of `token` spent inside the current window, either account-wide
+/// or towards a single destination. Uncapped dimensions read as zero.
+pub fn spent(env: &Env, token: &Address, destination: Option<Address>) -> i128 {
+    let key = match destination {
+        Some(to) => DataKey::DestinationSpend(token.clone(), to),
+        None => DataKey::AccountSpend(token.clone()),
+    };
+    total(&read_history(env, &key))
+}
@@ -0,0 +1,108 @@
+use soroban_sdk::{contractclient, contracttype, token, Address, Env, Symbol};
+
+use crate::fees::BPS_DENOMINATOR;
+use crate::{DataKey, RemittanceError};
+
+/// SEP-40 asset identifier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Asset {
+    Stellar(Address),
+    Other(Symbol),
+}
+
+/// SEP-40 price record; `price` is scaled by the oracle's `decimals()`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriceData {
+    pub price: i128,
+    pub timestamp: u64,
+}
+
+#[allow(dead_code)]
+#[contractclient(name = "PriceOracleClient")]
+pub trait PriceOracle {
+    fn lastprice(env: Env, asset: Asset) -> Option<PriceData>;
+}
+
+/// Price oracle guarding forex swaps. Every swap must ask for at least the
+/// oracle-implied output less `max_deviation_bps`, using prices no older
+/// than `max_age` seconds.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OracleConfig {
+    pub address: Address,
+    pub max_deviation_bps: u32,
+    pub max_age: u64,
+}
+
+impl OracleConfig {
+    pub fn validate(&self) -> Result<(), RemittanceError> {
+        if self.max_deviation_bps > BPS_DENOMINATOR || self.max_age == 0 {
+            return Err(RemittanceError::InvalidAmount);
+        }
+        Ok(())
+    }
+}
+
+pub fn read(env: &Env) -> Option<OracleConfig> {
+    env.storage().instance().get(&DataKey::Oracle)
+}
+
+pub fn write(env: &Env, config: &Option<OracleConfig>) {
+    match config {
+        Some(config) => env.storage().instance().set(&DataKey::Oracle, config),
+        None => env.storage().instance().remove(&DataKey::Oracle),
+    }
+}
+
+fn fresh_price(
+    env: &Env,
+    oracle: &PriceOracleClient,
+    token: &Address,
+    max_age: u64,
+) -> Result<i128, RemittanceError> {
+    let data = oracle
+        .lastprice(&Asset::Stellar(token.clone()))
+        .ok_or(RemittanceError::OracleUnavailable)?;
+    if data.price <= 0 {
+        return Err(RemittanceError::OracleUnavailable);
+    }
+    let now = env.ledger().timestamp();
+    if data.timestamp > now || now - data.timestamp > max_age {
+        return Err(RemittanceError::OracleStale);
+    }
+    Ok(data.price)
+}
+
+/// Smallest acceptable output for swapping `amount_in` of `input` into
+/// `output`, or `None` when no oracle is configured.
+pub fn min_amount_out(
+    env: &Env,
+    input: &Address,
+    output: &Address,
+    amount_in: i128,
+) -> Result<Option<i128>, RemittanceError> {
+    let Some(config) = read(env) else {
+        return Ok(None);
+    };
+    let oracle = PriceOracleClient::new(env, &config.address);
+    let price_in = fresh_price(env, &oracle, input, config.max_age)?;
+    let price_out = fresh_price(env, &oracle, output, config.max_age)?;
+    let decimals_in = token::Client::new(env, input).decimals();
+    let decimals_out = token::Client::new(env, output).decimals();
+
+    // amount_in * price_in / price_out, rescaled between token decimals
+    let expected = amount_in
+        .checked_mul(price_in)
+        .and_then(|value| value.checked_mul(10i128.checked_pow(decimals_out)?))
+        .and_then(|value| value.checked_div(price_out))
+        .and_then(|value| value.checked_div(10i128.checked_pow(decimals_in)?))
+        .ok_or(RemittanceError::InvalidAmount)?;
+    let tolerance = (BPS_DENOMINATOR - config.max_deviation_bps) as i128;
+    let floor = expected
+        .checked_mul(tolerance)
+        .ok_or(RemittanceError::InvalidAmount)?
+        / BPS_DENOMINATOR as i128;
+    Ok(Some(floor))
+}
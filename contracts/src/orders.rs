@@ -0,0 +1,98 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+use crate::{extend_persistent_ttl, DataKey, RemittanceError};
+
+/// Most orders that may be active or paused at once, which bounds the work
+/// done by a single `execute_due_orders` call.
+pub const MAX_ACTIVE_ORDERS: u32 = 25;
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OrderStatus {
+    Active,
+    Paused,
+    Cancelled,
+    Completed,
+}
+
+/// A recurring payment. `next_run` is the timestamp of the next payment
+/// owed; each execution advances it by `interval`, so a keeper that falls
+/// behind catches up one payment per call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StandingOrder {
+    pub id: u32,
+    pub destination: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub interval: u64,
+    pub next_run: u64,
+    /// No payment falls due at or after this timestamp.
+    pub end: Option<u64>,
+    pub max_runs: Option<u32>,
+    pub runs: u32,
+    pub last_run: u64,
+    pub status: OrderStatus,
+}
+
+impl StandingOrder {
+    /// True once the schedule has no payments left.
+    pub fn is_finished(&self) -> bool {
+        let out_of_runs = self.max_runs.is_some_and(|max| self.runs >= max);
+        let past_end = self.end.is_some_and(|end| self.next_run >= end);
+        out_of_runs || past_end
+    }
+}
+
+pub fn read(env: &Env, id: u32) -> Option<StandingOrder> {
+    let key = DataKey::Order(id);
+    let order = env.storage().persistent().get::<_, StandingOrder>(&key);
+    if order.is_some() {
+        extend_persistent_ttl(env, &key);
+    }
+    order
+}
+
+pub fn write(env: &Env, order: &StandingOrder) {
+    let key = DataKey::Order(order.id);
+    env.storage().persistent().set(&key, order);
+    extend_persistent_ttl(env, &key);
+}
+
+pub fn next_id(env: &Env) -> u32 {
+    let id = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&DataKey::NextOrderId)
+        .unwrap_or(0);
+    env.storage()
+        .instance()
+        .set(&DataKey::NextOrderId, &(id + 1));
+    id
+}
+
+/// Ids of orders that are active or paused, oldest first.
+pub fn open_ids(env: &Env) -> Vec<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::OpenOrders)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn open(env: &Env, id: u32) -> Result<(), RemittanceError> {
+    let mut ids = open_ids(env);
+    if ids.len() >= MAX_ACTIVE_ORDERS {
+        return Err(RemittanceError::LimitExceeded);
+    }
+    ids.push_back(id);
+    env.storage().instance().set(&DataKey::OpenOrders, &ids);
+    Ok(())
+}
+
+pub fn close(env: &Env, id: u32) {
+    let mut ids = open_ids(env);
+    if let Some(index) = ids.first_index_of(id) {
+        ids.remove(index);
+        env.storage().instance().set(&DataKey::OpenOrders, &ids);
+    }
+}
@@ -0,0 +1,47 @@
+use soroban_sdk::{contracttype, Address, BytesN, Env, String};
+
+use crate::{DataKey, DAY_IN_LEDGERS};
+
+/// How long an executed reference is remembered, and rejected if reused.
+pub const REFERENCE_TTL_LEDGERS: u32 = 30 * DAY_IN_LEDGERS;
+
+/// Longest memo, in bytes, stored alongside a payment.
+pub const MAX_MEMO_LEN: u32 = 64;
+
+/// Record of a transfer made under a caller-supplied reference. `amount`
+/// is what left the account; `to` received `net` after the corridor `fee`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Payment {
+    pub reference: BytesN<32>,
+    pub to: Address,
+    pub token: Address,
+    pub amount: i128,
+    pub fee: i128,
+    pub net: i128,
+    pub memo: Option<String>,
+    pub timestamp: u64,
+    pub ledger: u32,
+}
+
+/// References live in temporary storage, so a reference can be used again
+/// once its entry has expired.
+pub fn read(env: &Env, reference: &BytesN<32>) -> Option<Payment> {
+    env.storage()
+        .temporary()
+        .get(&DataKey::Payment(reference.clone()))
+}
+
+pub fn exists(env: &Env, reference: &BytesN<32>) -> bool {
+    env.storage()
+        .temporary()
+        .has(&DataKey::Payment(reference.clone()))
+}
+
+pub fn write(env: &Env, payment: &Payment) {
+    let key = DataKey::Payment(payment.reference.clone());
+    env.storage().temporary().set(&key, payment);
+    env.storage()
+        .temporary()
+        .extend_ttl(&key, REFERENCE_TTL_LEDGERS, REFERENCE_TTL_LEDGERS);
+}
@@ -0,0 +1,70 @@
+use soroban_sdk::{Address, Env, Vec};
+
+use crate::{DataKey, RemittanceError};
+
+/// Longest swap path accepted, counting both endpoints.
+pub const MAX_PATH_LEN: u32 = 5;
+
+/// Most admin-approved routes stored at once.
+pub const MAX_ROUTES: u32 = 20;
+
+/// Full paths the admin has approved, whatever their intermediates.
+pub fn read_routes(env: &Env) -> Vec<Vec<Address>> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ForexRoutes)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn write_routes(env: &Env, routes: &Vec<Vec<Address>>) {
+    env.storage().instance().set(&DataKey::ForexRoutes, routes);
+}
+
+/// Tokens any caller-supplied path may hop through.
+pub fn read_intermediates(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::ForexIntermediates)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn write_intermediates(env: &Env, tokens: &Vec<Address>) {
+    env.storage()
+        .instance()
+        .set(&DataKey::ForexIntermediates, tokens);
+}
+
+/// Check the shape of a path: between 2 and `MAX_PATH_LEN` tokens, none
+/// repeated, starting and ending in a token in `endpoints`.
+pub fn validate(path: &Vec<Address>, endpoints: &Vec<Address>) -> Result<(), RemittanceError> {
+    if path.len() < 2 || path.len() > MAX_PATH_LEN {
+        return Err(RemittanceError::InvalidPath);
+    }
+    for (index, token) in path.iter().enumerate() {
+        if path.first_index_of(&token) != Some(index as u32) {
+            return Err(RemittanceError::InvalidPath);
+        }
+    }
+    let first = path.first().ok_or(RemittanceError::InvalidPath)?;
+    let last = path.last().ok_or(RemittanceError::InvalidPath)?;
+    if !endpoints.contains(&first) || !endpoints.contains(&last) {
+        return Err(RemittanceError::InvalidPath);
+    }
+    Ok(())
+}
+
+/// A path may be swapped along if the admin approved it as a whole, or if
+/// every token between its endpoints is an allowed intermediate.
+pub fn ensure_allowed(env: &Env, path: &Vec<Address>) -> Result<(), RemittanceError> {
+    if read_routes(env).contains(path) {
+        return Ok(());
+    }
+    let intermediates = read_intermediates(env);
+    for index in 1..path.len() - 1 {
+        let hop = path.get(index).ok_or(RemittanceError::InvalidPath)?;
+        if !intermediates.contains(&hop) {
+            return Err(RemittanceError::NotAllowed);
+        }
+    }
+    Ok(())
+}
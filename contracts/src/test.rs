@@ -1,10 +1,13 @@
 #![cfg(test)]
 
 use super::*;
+use soroban_sdk::testutils::{AuthorizedFunction, BytesN as _, Events, Ledger};
 use soroban_sdk::token::{Client as TokenClient, StellarAssetClient as TokenAdminClient};
 use soroban_sdk::{
-    contract, contractimpl, testutils::Address as _, vec, Address, Env, String, Vec,
+    contract, contractimpl, testutils::Address as _, vec, Address, Bytes, BytesN, Env, Event,
+    IntoVal, String, Symbol, Vec,
 };
+use spending_limit_policy::{PayoutLimitParams, SpendingLimitPolicy};
 
 // Helper to create a test token
 fn create_token_contract<'a>(e: &Env, admin: &Address) -> (TokenClient<'a>, TokenAdminClient<'a>) {
@@ -34,8 +37,36 @@ fn setup_smart_account(e: &Env) -> (Address, Address, Address, Address, Address,
     )
 }
 
+// Helper to register a MockRouter holding reserves of both forex tokens
+fn create_mock_router(e: &Env, usdc: &TokenAdminClient, eurc: &TokenAdminClient) -> Address {
+    let router = e.register(MockRouter, ());
+    usdc.mint(&router, &1_000_000);
+    eurc.mint(&router, &1_000_000);
+    router
+}
+
+// Helper to check a call was refused by the host, as a missing
+// authorization is, rather than by one of the contract's own checks
+fn assert_auth_error<T: core::fmt::Debug, E: core::fmt::Debug>(
+    result: Result<T, Result<E, soroban_sdk::InvokeError>>,
+) {
+    match result {
+        Err(Err(soroban_sdk::InvokeError::Abort)) => {}
+        other => panic!("expected an authorization failure, got {:?}", other),
+    }
+}
+
+// Helper to check the most recent event was published by `contract`
+fn assert_last_event(e: &Env, contract: &Address, event: impl Event) {
+    let last = e.events().all().last().unwrap();
+    assert_eq!(
+        vec![e, last],
+        vec![e, (contract.clone(), event.topics(e), event.data(e))]
+    );
+}
+
 #[test]
-fn test_init_creates_smart_account() {
+fn test_constructor_creates_smart_account() {
     let e = Env::default();
     e.mock_all_auths();
 
@@ -44,24 +75,24 @@ fn test_init_creates_smart_account() {
     let (token_client, _) = create_token_contract(&e, &token_admin);
     let token_address = token_client.address.clone();
 
-    // Register smart account contract
-    let contract_id = e.register(RemittanceAccount, ());
-    let client = RemittanceAccountClient::new(&e, &contract_id);
-
-    // Initialize account A
+    // Deploy account A; the constructor initializes it
     let destinations = vec![&e, account_b.clone(), account_c.clone(), account_d.clone()];
-    client.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let contract_id = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client = RemittanceAccountClient::new(&e, &contract_id);
 
     // Verify label was set correctly
     let label = client.get_label();
     assert_eq!(label, String::from_str(&e, "A"));
 
-    // Verify at least one context rule was created during init
+    // Verify at least one context rule was created by the constructor
     let rules = client.get_context_rules(&ContextRuleType::Default);
     assert_eq!(rules.len(), 1);
 
@@ -69,6 +100,49 @@ fn test_init_creates_smart_account() {
     assert_eq!(rule.name, String::from_str(&e, "Admin Rule"));
 }
 
+#[test]
+fn test_constructor_cannot_be_reinvoked() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _account_a, account_b, account_c, account_d) = setup_smart_account(&e);
+    let attacker = Address::generate(&e);
+
+    let (token_client, _) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    let destinations = vec![&e, account_b.clone(), account_c.clone(), account_d.clone()];
+    let contract_id = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client = RemittanceAccountClient::new(&e, &contract_id);
+
+    // Setup happens at deployment; there is no later call to front-run
+    let hijacked = vec![&e, attacker.clone()];
+    let result = e.try_invoke_contract::<(), soroban_sdk::Error>(
+        &contract_id,
+        &Symbol::new(&e, "__constructor"),
+        (
+            attacker,
+            token_address,
+            hijacked,
+            String::from_str(&e, "Hijacked"),
+        )
+            .into_val(&e),
+    );
+    assert!(result.is_err());
+
+    assert_eq!(client.get_label(), String::from_str(&e, "A"));
+    let rules = client.get_context_rules(&ContextRuleType::Default);
+    assert_eq!(rules.len(), 1);
+}
+
 #[test]
 fn test_execute_transfer_to_allowed_destination() {
     let e = Env::default();
@@ -81,21 +155,22 @@ fn test_execute_transfer_to_allowed_destination() {
     let token_address = token_client.address.clone();
 
     // Register and initialize account A
-    let account_a = e.register(RemittanceAccount, ());
-    let client_a = RemittanceAccountClient::new(&e, &account_a);
-
     let destinations = vec![
         &e,
         account_b_addr.clone(),
         account_c_addr.clone(),
         account_d_addr.clone(),
     ];
-    client_a.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
 
     // Mint tokens to account A
     token_admin_client.mint(&account_a, &1000);
@@ -122,21 +197,22 @@ fn test_execute_transfer_to_disallowed_destination_fails() {
     let token_address = token_client.address.clone();
 
     // Register and initialize account A
-    let account_a = e.register(RemittanceAccount, ());
-    let client_a = RemittanceAccountClient::new(&e, &account_a);
-
     let destinations = vec![
         &e,
         account_b_addr.clone(),
         account_c_addr.clone(),
         account_d_addr.clone(),
     ];
-    client_a.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
 
     // Mint tokens to account A
     token_admin_client.mint(&account_a, &1000);
@@ -163,21 +239,22 @@ fn test_execute_transfer_to_self_fails() {
     let token_address = token_client.address.clone();
 
     // Register and initialize account A
-    let account_a = e.register(RemittanceAccount, ());
-    let client_a = RemittanceAccountClient::new(&e, &account_a);
-
     let destinations = vec![
         &e,
         account_b_addr.clone(),
         account_c_addr.clone(),
         account_d_addr.clone(),
     ];
-    client_a.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
 
     // Mint tokens to account A
     token_admin_client.mint(&account_a, &1000);
@@ -189,32 +266,162 @@ fn test_execute_transfer_to_self_fails() {
 }
 
 #[test]
-fn test_admin_withdraw() {
+fn test_execute_transfer_requires_account_auth() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let (admin, token_admin, _account_a_addr, account_b_addr, account_c_addr, account_d_addr) =
+    let (admin, token_admin, _, account_b_addr, account_c_addr, account_d_addr) =
         setup_smart_account(&e);
 
     let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
     let token_address = token_client.address.clone();
 
     // Register and initialize account A
-    let account_a = e.register(RemittanceAccount, ());
+    let destinations = vec![
+        &e,
+        account_b_addr.clone(),
+        account_c_addr.clone(),
+        account_d_addr.clone(),
+    ];
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+
+    token_admin_client.mint(&account_a, &1000);
+
+    // The transfer is authorized by the smart account itself
+    client_a.execute_transfer(&account_b_addr, &100);
+    let auths = e.auths();
+    assert_eq!(auths.len(), 1);
+    let (authorizer, invocation) = auths.first().unwrap();
+    assert_eq!(authorizer, &account_a);
+    assert_eq!(
+        invocation.function,
+        AuthorizedFunction::Contract((
+            account_a.clone(),
+            Symbol::new(&e, "execute_transfer"),
+            (account_b_addr.clone(), 100_i128).into_val(&e),
+        ))
+    );
+
+    // Without the account's authorization the transfer is rejected
+    e.set_auths(&[]);
+    let result = client_a.try_execute_transfer(&account_b_addr, &100);
+    assert_auth_error(result);
+
+    assert_eq!(token_client.balance(&account_a), 900);
+    assert_eq!(token_client.balance(&account_b_addr), 100);
+}
+
+#[test]
+fn test_multisig_votes_require_account_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (token_client, _) = create_token_contract(&e, &token_admin);
+    let treasury = e.register(MockTreasury, ());
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_client.address.clone(),
+            vec![&e, account_b_addr.clone(), treasury.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+
+    client_a.initiate_multisig_withdraw(&treasury, &token_client.address, &account_b_addr, &100);
+    client_a.approve_multisig_withdraw(&treasury, &0);
+
+    // The treasury would accept the account's vote as its direct invoker,
+    // so the account must authorize it first
+    e.set_auths(&[]);
+    let result = client_a.try_initiate_multisig_withdraw(
+        &treasury,
+        &token_client.address,
+        &account_b_addr,
+        &100,
+    );
+    assert_auth_error(result);
+    let result = client_a.try_approve_multisig_withdraw(&treasury, &0);
+    assert_auth_error(result);
+}
+
+#[test]
+fn test_execute_forex_transfer_requires_account_auth() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+
+    let destinations = vec![&e, account_b_addr.clone()];
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
+    );
     let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+
+    usdc_admin_client.mint(&account_a, &1_000);
+    eurc_admin_client.mint(&account_a, &1_000);
+
+    // Drop the mocked authorizations before attempting the swap
+    e.set_auths(&[]);
+    let deadline: u64 = 600;
+    let result = client_a.try_execute_forex_transfer(&account_b_addr, &200, &200, &deadline, &true);
+    assert_auth_error(result);
+
+    assert_eq!(eurc_client.balance(&account_b_addr), 0);
+    assert_eq!(eurc_client.balance(&account_a), 1_000);
+}
+
+#[test]
+fn test_admin_withdraw() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _account_a_addr, account_b_addr, account_c_addr, account_d_addr) =
+        setup_smart_account(&e);
+
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
 
+    // Register and initialize account A
     let destinations = vec![
         &e,
         account_b_addr.clone(),
         account_c_addr.clone(),
         account_d_addr.clone(),
     ];
-    client_a.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
 
     // Mint tokens to account A
     token_admin_client.mint(&account_a, &1000);
@@ -228,6 +435,88 @@ fn test_admin_withdraw() {
     assert_eq!(token_client.balance(&admin), 500);
 }
 
+#[test]
+fn test_transfer_in_supported_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    assert_eq!(
+        client_a.get_supported_tokens(),
+        vec![&e, usdc_client.address.clone()]
+    );
+
+    usdc_admin_client.mint(&account_a, &1_000);
+    eurc_admin_client.mint(&account_a, &1_000);
+
+    // EURC is rejected until the admin adds it
+    let result = client_a.try_execute_token_transfer(&eurc_client.address, &account_b_addr, &100);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::TokenNotSupported)));
+    let result = client_a.try_admin_withdraw_token(&eurc_client.address, &100);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::TokenNotSupported)));
+
+    client_a.add_supported_token(&eurc_client.address);
+    assert_eq!(
+        client_a.get_supported_tokens(),
+        vec![&e, usdc_client.address.clone(), eurc_client.address.clone()]
+    );
+
+    client_a.execute_token_transfer(&eurc_client.address, &account_b_addr, &100);
+    client_a.execute_token_transfer(&usdc_client.address, &account_b_addr, &50);
+    client_a.admin_withdraw_token(&eurc_client.address, &200);
+
+    assert_eq!(eurc_client.balance(&account_b_addr), 100);
+    assert_eq!(usdc_client.balance(&account_b_addr), 50);
+    assert_eq!(eurc_client.balance(&admin), 200);
+    assert_eq!(eurc_client.balance(&account_a), 700);
+
+    // Removing EURC blocks it again
+    client_a.remove_supported_token(&eurc_client.address);
+    let result = client_a.try_execute_token_transfer(&eurc_client.address, &account_b_addr, &100);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::TokenNotSupported)));
+}
+
+#[test]
+fn test_primary_token_cannot_be_removed() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, _) = create_token_contract(&e, &token_admin);
+    let unknown_token = Address::generate(&e);
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+
+    let result = client_a.try_remove_supported_token(&usdc_client.address);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
+
+    let result = client_a.try_remove_supported_token(&unknown_token);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::TokenNotSupported)));
+}
+
 #[test]
 fn test_update_destinations() {
     let e = Env::default();
@@ -241,21 +530,22 @@ fn test_update_destinations() {
     let token_address = token_client.address.clone();
 
     // Register and initialize account A
-    let account_a = e.register(RemittanceAccount, ());
-    let client_a = RemittanceAccountClient::new(&e, &account_a);
-
     let destinations = vec![
         &e,
         account_b_addr.clone(),
         account_c_addr.clone(),
         account_d_addr.clone(),
     ];
-    client_a.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
 
     // Mint tokens
     token_admin_client.mint(&account_a, &1000);
@@ -283,110 +573,191 @@ fn test_update_destinations() {
 }
 
 #[test]
-fn test_multiple_transfers_between_accounts() {
+fn test_transfer_and_admin_actions_emit_events() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let (admin, token_admin, _, _, _, _) = setup_smart_account(&e);
+    let (admin, token_admin, _, account_b_addr, account_c_addr, _) = setup_smart_account(&e);
+    let router = Address::generate(&e);
+    let counter_token = Address::generate(&e);
 
     let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
     let token_address = token_client.address.clone();
 
-    // Create 4 smart accounts
-    let account_a = e.register(RemittanceAccount, ());
-    let account_b = e.register(RemittanceAccount, ());
-    let account_c = e.register(RemittanceAccount, ());
-    let account_d = e.register(RemittanceAccount, ());
+    let label = String::from_str(&e, "A");
 
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone()],
+            label.clone(),
+        ),
+    );
     let client_a = RemittanceAccountClient::new(&e, &account_a);
-    let client_b = RemittanceAccountClient::new(&e, &account_b);
-    let client_c = RemittanceAccountClient::new(&e, &account_c);
-    let client_d = RemittanceAccountClient::new(&e, &account_d);
-
-    // Initialize each account with the others as destinations
-    let dest_a = vec![&e, account_b.clone(), account_c.clone(), account_d.clone()];
-    let dest_b = vec![&e, account_a.clone(), account_c.clone(), account_d.clone()];
-    let dest_c = vec![&e, account_a.clone(), account_b.clone(), account_d.clone()];
-    let dest_d = vec![&e, account_a.clone(), account_b.clone(), account_c.clone()];
-
-    client_a.init(&admin, &token_address, &dest_a, &String::from_str(&e, "A"));
-    client_b.init(&admin, &token_address, &dest_b, &String::from_str(&e, "B"));
-    client_c.init(&admin, &token_address, &dest_c, &String::from_str(&e, "C"));
-    client_d.init(&admin, &token_address, &dest_d, &String::from_str(&e, "D"));
-
-    // Mint initial balances
     token_admin_client.mint(&account_a, &1000);
-    token_admin_client.mint(&account_b, &1000);
-    token_admin_client.mint(&account_c, &1000);
-    token_admin_client.mint(&account_d, &1000);
-
-    // A -> B: 100
-    client_a.execute_transfer(&account_b, &100);
-    assert_eq!(token_client.balance(&account_a), 900);
-    assert_eq!(token_client.balance(&account_b), 1100);
 
-    // B -> C: 200
-    client_b.execute_transfer(&account_c, &200);
-    assert_eq!(token_client.balance(&account_b), 900);
-    assert_eq!(token_client.balance(&account_c), 1200);
+    client_a.execute_transfer(&account_b_addr, &100);
+    assert_last_event(
+        &e,
+        &account_a,
+        TransferExecuted {
+            label: label.clone(),
+            to: account_b_addr.clone(),
+            token: token_address.clone(),
+            amount: 100,
+        },
+    );
 
-    // C -> D: 150
-    client_c.execute_transfer(&account_d, &150);
-    assert_eq!(token_client.balance(&account_c), 1050);
-    assert_eq!(token_client.balance(&account_d), 1150);
+    client_a.admin_withdraw(&50);
+    assert_last_event(
+        &e,
+        &account_a,
+        AdminWithdrawal {
+            label: label.clone(),
+            to: admin.clone(),
+            token: token_address.clone(),
+            amount: 50,
+        },
+    );
 
-    // D -> A: 250
-    client_d.execute_transfer(&account_a, &250);
-    assert_eq!(token_client.balance(&account_d), 900);
-    assert_eq!(token_client.balance(&account_a), 1150);
+    let destinations = vec![&e, account_b_addr.clone(), account_c_addr.clone()];
+    client_a.update_destinations(&destinations);
+    assert_last_event(
+        &e,
+        &account_a,
+        DestinationsUpdated {
+            label: label.clone(),
+            destinations,
+        },
+    );
 
-    // Verify total supply unchanged
-    let total = token_client.balance(&account_a)
-        + token_client.balance(&account_b)
-        + token_client.balance(&account_c)
-        + token_client.balance(&account_d);
-    assert_eq!(total, 4000);
+    client_a.configure_forex(&router, &counter_token, &None);
+    assert_last_event(
+        &e,
+        &account_a,
+        ForexConfigured {
+            label,
+            router,
+            counter_token,
+            oracle: None,
+        },
+    );
 }
 
 #[test]
-fn test_get_context_rules() {
+fn test_destination_registry() {
     let e = Env::default();
     e.mock_all_auths();
 
-    let (admin, token_admin, _account_a_addr, account_b_addr, account_c_addr, account_d_addr) =
+    let (admin, token_admin, _, account_b_addr, account_c_addr, account_d_addr) =
         setup_smart_account(&e);
+    let vendor = Address::generate(&e);
 
-    let (token_client, _) = create_token_contract(&e, &token_admin);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
     let token_address = token_client.address.clone();
 
-    // Register and initialize account A
-    let account_a = e.register(RemittanceAccount, ());
-    let client_a = RemittanceAccountClient::new(&e, &account_a);
-
     let destinations = vec![
         &e,
         account_b_addr.clone(),
         account_c_addr.clone(),
         account_d_addr.clone(),
     ];
-    client_a.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &1000);
+    assert_eq!(client_a.destination_count(), 3);
+
+    // Register a new payee with metadata
+    client_a.add_destination(
+        &vendor,
+        &String::from_str(&e, "Vendor"),
+        &String::from_str(&e, "GB"),
+        &Some(token_address.clone()),
+    );
+    let entry = client_a.get_destination(&vendor);
+    assert_eq!(entry.label, String::from_str(&e, "Vendor"));
+    assert_eq!(entry.corridor, String::from_str(&e, "GB"));
+    assert_eq!(entry.preferred_token, Some(token_address.clone()));
+    assert!(entry.enabled);
+    client_a.execute_transfer(&vendor, &100);
+    assert_eq!(token_client.balance(&vendor), 100);
+
+    // Paginate through the registry
+    let first_page = client_a.list_destinations(&0, &2);
+    assert_eq!(first_page.len(), 2);
+    assert_eq!(first_page.get(0).unwrap().address, account_b_addr);
+    let second_page = client_a.list_destinations(&2, &2);
+    assert_eq!(second_page.len(), 2);
+    assert_eq!(second_page.get(1).unwrap().address, vendor);
+    assert_eq!(client_a.list_destinations(&4, &2).len(), 0);
+
+    // Disabled destinations keep their entry but cannot be paid
+    client_a.set_destination_enabled(&vendor, &false);
+    let result = client_a.try_execute_transfer(&vendor, &100);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
+    client_a.set_destination_enabled(&vendor, &true);
+    client_a.execute_transfer(&vendor, &100);
+
+    // Removing moves the last entry into the freed slot
+    client_a.remove_destination(&account_b_addr);
+    assert_eq!(client_a.destination_count(), 3);
+    let page = client_a.list_destinations(&0, &10);
+    assert_eq!(page.len(), 3);
+    assert_eq!(page.get(0).unwrap().address, vendor);
+    assert_eq!(page.get(1).unwrap().address, account_c_addr);
+    assert_eq!(page.get(2).unwrap().address, account_d_addr);
+    let result = client_a.try_execute_transfer(&account_b_addr, &100);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
 
-    // Get default context rules (should have 1 from init)
-    let rules = client_a.get_context_rules(&ContextRuleType::Default);
-    assert_eq!(rules.len(), 1);
+    let result = client_a.try_remove_destination(&account_b_addr);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::DestinationNotFound)));
+    let result = client_a.try_set_destination_enabled(&account_b_addr, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::DestinationNotFound)));
+}
 
-    // Get the first rule
-    let rule = client_a.get_context_rule(&0);
-    assert_eq!(rule.name, String::from_str(&e, "Admin Rule"));
+#[test]
+fn test_add_destination_rejects_unsupported_preferred_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (token_client, _) = create_token_contract(&e, &token_admin);
+    let unknown_token = Address::generate(&e);
+    let vendor = Address::generate(&e);
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_client.address.clone(),
+            vec![&e, account_b_addr],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+
+    let result = client_a.try_add_destination(
+        &vendor,
+        &String::from_str(&e, "Vendor"),
+        &String::from_str(&e, "GB"),
+        &Some(unknown_token),
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::TokenNotSupported)));
+    assert_eq!(client_a.destination_count(), 1);
 }
 
 #[test]
-fn test_zero_amount_transfer() {
+fn test_spending_limits_on_transfers() {
     let e = Env::default();
     e.mock_all_auths();
 
@@ -396,37 +767,1733 @@ fn test_zero_amount_transfer() {
     let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
     let token_address = token_client.address.clone();
 
-    // Register and initialize account A
-    let account_a = e.register(RemittanceAccount, ());
-    let client_a = RemittanceAccountClient::new(&e, &account_a);
-
     let destinations = vec![
         &e,
         account_b_addr.clone(),
         account_c_addr.clone(),
         account_d_addr.clone(),
     ];
-    client_a.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &10_000);
 
-    // Mint tokens
-    token_admin_client.mint(&account_a, &1000);
+    client_a.set_spending_limits(
+        &token_address,
+        &SpendingLimits {
+            per_transaction: Some(500),
+            per_destination: Some(800),
+            per_account: Some(1_500),
+        },
+    );
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    // Per-transaction maximum
+    let result = client_a.try_execute_transfer(&account_b_addr, &501);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::LimitExceeded)));
+
+    // Per-destination cap within the window
+    client_a.execute_transfer(&account_b_addr, &500);
+    client_a.execute_transfer(&account_b_addr, &300);
+    let result = client_a.try_execute_transfer(&account_b_addr, &1);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::LimitExceeded)));
+    assert_eq!(
+        client_a.get_rolling_spend(&token_address, &Some(account_b_addr.clone())),
+        800
+    );
 
-    // Transfer 0 amount (should succeed but not move anything)
-    let result = client_a.try_execute_transfer(&account_b_addr, &0);
-    assert!(result.is_ok());
+    // Account-wide cap across destinations, including admin withdrawals
+    client_a.execute_transfer(&account_c_addr, &500);
+    let result = client_a.try_execute_transfer(&account_d_addr, &201);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::LimitExceeded)));
+    let result = client_a.try_admin_withdraw(&201);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::LimitExceeded)));
+    client_a.admin_withdraw(&200);
+    assert_eq!(client_a.get_rolling_spend(&token_address, &None), 1_500);
+
+    // Spend is bucketed by the hour and rolls out of the window once its
+    // whole bucket is more than 24h old
+    e.ledger()
+        .with_mut(|li| li.timestamp = 1_000 + 24 * 60 * 60);
+    assert_eq!(client_a.get_rolling_spend(&token_address, &None), 1_500);
+    e.ledger()
+        .with_mut(|li| li.timestamp = 60 * 60 + 24 * 60 * 60);
+    assert_eq!(client_a.get_rolling_spend(&token_address, &None), 0);
+    client_a.execute_transfer(&account_b_addr, &500);
+
+    assert_eq!(token_client.balance(&account_b_addr), 1_300);
+    assert_eq!(token_client.balance(&account_c_addr), 500);
+    assert_eq!(token_client.balance(&admin), 200);
+}
 
-    // Verify balances unchanged
-    assert_eq!(token_client.balance(&account_a), 1000);
-    assert_eq!(token_client.balance(&account_b_addr), 0);
+// Run the account's `__check_auth` for a single call made on itself,
+// signed by `signer`
+fn account_authorizes(
+    e: &Env,
+    account: &Address,
+    signer: &Address,
+    fn_name: &str,
+    args: Vec<Val>,
+) -> bool {
+    let context = Context::Contract(ContractContext {
+        contract: account.clone(),
+        fn_name: Symbol::new(e, fn_name),
+        args,
+    });
+    let signatures = Signatures(Map::from_array(
+        e,
+        [(Signer::Delegated(signer.clone()), Bytes::new(e))],
+    ));
+    e.try_invoke_contract_check_auth::<SmartAccountError>(
+        account,
+        &BytesN::random(e),
+        signatures.into_val(e),
+        &vec![e, context],
+    )
+    .is_ok()
 }
 
 #[test]
-fn test_transfer_more_than_balance_fails() {
+fn test_spending_limit_policy_caps_relayer_payouts() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (token_client, _) = create_token_contract(&e, &token_admin);
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+
+    // Relayer rule over the account's own calls, capped at 500 per 100 ledgers
+    let relayer = Address::generate(&e);
+    let policy = e.register(SpendingLimitPolicy, ());
+    let params = PayoutLimitParams {
+        spending_limit: 500,
+        period_ledgers: 100,
+        token: token_client.address.clone(),
+    };
+    client_a.add_context_rule(
+        &ContextRuleType::CallContract(account_a.clone()),
+        &String::from_str(&e, "Relayer"),
+        &None,
+        &vec![&e, Signer::Delegated(relayer.clone())],
+        &Map::from_array(&e, [(policy, params.into_val(&e))]),
+    );
+
+    let payout = |amount: i128| (account_b_addr.clone(), amount).into_val(&e);
+    assert!(account_authorizes(
+        &e,
+        &account_a,
+        &relayer,
+        "execute_transfer",
+        payout(300)
+    ));
+    assert!(account_authorizes(
+        &e,
+        &account_a,
+        &relayer,
+        "execute_transfer",
+        payout(200)
+    ));
+    assert!(!account_authorizes(
+        &e,
+        &account_a,
+        &relayer,
+        "execute_transfer",
+        payout(1)
+    ));
+
+    // The relayer cannot reach account management through the payout rule
+    let remove_rule = (0_u32,).into_val(&e);
+    assert!(!account_authorizes(
+        &e,
+        &account_a,
+        &relayer,
+        "remove_context_rule",
+        remove_rule
+    ));
+
+    // The admin rule carries no policy
+    assert!(account_authorizes(
+        &e,
+        &account_a,
+        &admin,
+        "execute_transfer",
+        payout(5_000)
+    ));
+
+    e.ledger().with_mut(|li| li.sequence_number += 101);
+    assert!(account_authorizes(
+        &e,
+        &account_a,
+        &relayer,
+        "execute_transfer",
+        payout(500)
+    ));
+}
+
+#[test]
+fn test_spending_limits_on_forex_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+    usdc_admin_client.mint(&account_a, &1_000);
+    eurc_admin_client.mint(&account_a, &1_000);
+
+    // Limits apply to the token being spent
+    client_a.set_spending_limits(
+        &usdc_client.address,
+        &SpendingLimits {
+            per_transaction: Some(100),
+            per_destination: None,
+            per_account: None,
+        },
+    );
+
+    let deadline: u64 = 600;
+    let result = client_a.try_execute_forex_transfer(&account_b_addr, &200, &200, &deadline, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::LimitExceeded)));
+
+    client_a.execute_forex_transfer(&account_b_addr, &100, &100, &deadline, &true);
+    client_a.execute_forex_transfer(&account_b_addr, &200, &200, &deadline, &false);
+    assert_eq!(eurc_client.balance(&account_b_addr), 100);
+    assert_eq!(usdc_client.balance(&account_b_addr), 200);
+
+    let result = client_a.try_set_spending_limits(
+        &usdc_client.address,
+        &SpendingLimits {
+            per_transaction: Some(-1),
+            per_destination: None,
+            per_account: None,
+        },
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidAmount)));
+}
+
+#[test]
+fn test_standing_order_pays_on_schedule() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &1_000);
+
+    let week: u64 = 7 * 24 * 60 * 60;
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    let id = client_a.create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &100,
+        &week,
+        &2_000,
+        &None,
+        &Some(3),
+    );
+    assert_eq!(client_a.list_standing_orders().len(), 1);
+
+    // Nothing is due before the start time
+    assert_eq!(client_a.execute_due_orders().len(), 0);
+
+    // The keeper needs no authorization
+    e.set_auths(&[]);
+    e.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client_a.execute_due_orders(), vec![&e, id]);
+    assert_last_event(
+        &e,
+        &account_a,
+        StandingOrderExecuted {
+            label: String::from_str(&e, "A"),
+            id,
+            to: account_b_addr.clone(),
+            token: token_address.clone(),
+            amount: 100,
+            run: 1,
+        },
+    );
+    // Already paid for this period
+    assert_eq!(client_a.execute_due_orders().len(), 0);
+
+    e.ledger().with_mut(|li| li.timestamp = 2_000 + week);
+    client_a.execute_due_orders();
+    let order = client_a.get_standing_order(&id);
+    assert_eq!(order.runs, 2);
+    assert_eq!(order.last_run, 2_000 + week);
+    assert_eq!(order.next_run, 2_000 + 2 * week);
+
+    // The last run completes the order and drops it from the open list
+    e.ledger().with_mut(|li| li.timestamp = 2_000 + 5 * week);
+    client_a.execute_due_orders();
+    assert_eq!(client_a.execute_due_orders().len(), 0);
+    let order = client_a.get_standing_order(&id);
+    assert_eq!(order.runs, 3);
+    assert_eq!(order.status, OrderStatus::Completed);
+    assert_eq!(client_a.list_standing_orders().len(), 0);
+    assert_eq!(token_client.balance(&account_b_addr), 300);
+}
+
+#[test]
+fn test_standing_order_pause_resume_cancel() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, account_c_addr, _) = setup_smart_account(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone(), account_c_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &1_000);
+
+    let day: u64 = 24 * 60 * 60;
+    let to_b = client_a.create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &100,
+        &day,
+        &0,
+        &Some(10 * day),
+        &None,
+    );
+    let to_c = client_a.create_standing_order(
+        &account_c_addr,
+        &token_address,
+        &50,
+        &day,
+        &0,
+        &None,
+        &None,
+    );
+
+    client_a.pause_standing_order(&to_b);
+    assert_last_event(
+        &e,
+        &account_a,
+        StandingOrderUpdated {
+            label: String::from_str(&e, "A"),
+            id: to_b,
+            status: OrderStatus::Paused,
+        },
+    );
+    assert_eq!(client_a.execute_due_orders(), vec![&e, to_c]);
+    let result = client_a.try_pause_standing_order(&to_b);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
+
+    // Runs missed while paused are not paid on resume
+    e.ledger().with_mut(|li| li.timestamp = 3 * day + 10);
+    client_a.resume_standing_order(&to_b);
+    assert_eq!(client_a.get_standing_order(&to_b).next_run, 4 * day);
+    assert_eq!(client_a.execute_due_orders(), vec![&e, to_c]);
+
+    // A disabled destination is skipped and retried later
+    client_a.set_destination_enabled(&account_c_addr, &false);
+    e.ledger().with_mut(|li| li.timestamp = 4 * day);
+    assert_eq!(client_a.execute_due_orders(), vec![&e, to_b]);
+    client_a.set_destination_enabled(&account_c_addr, &true);
+    assert_eq!(client_a.execute_due_orders(), vec![&e, to_c]);
+
+    client_a.cancel_standing_order(&to_c);
+    e.ledger().with_mut(|li| li.timestamp = 5 * day);
+    assert_eq!(client_a.execute_due_orders(), vec![&e, to_b]);
+    assert_eq!(
+        client_a.get_standing_order(&to_c).status,
+        OrderStatus::Cancelled
+    );
+    let result = client_a.try_resume_standing_order(&to_c);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
+
+    assert_eq!(token_client.balance(&account_b_addr), 200);
+    assert_eq!(token_client.balance(&account_c_addr), 150);
+}
+
+#[test]
+fn test_standing_order_resume_after_long_pause() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (token_client, _) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+
+    // A one-second order and a weekly one, both paused for a year
+    let secondly =
+        client_a.create_standing_order(&account_b_addr, &token_address, &1, &1, &100, &None, &None);
+    let weekly = client_a.create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &1,
+        &(7 * 24 * 60 * 60),
+        &0,
+        &None,
+        &None,
+    );
+    client_a.pause_standing_order(&secondly);
+    client_a.pause_standing_order(&weekly);
+
+    // The run landing exactly on `now` stays due
+    let year: u64 = 365 * 24 * 60 * 60;
+    e.ledger().with_mut(|li| li.timestamp = 100 + year);
+    client_a.resume_standing_order(&secondly);
+    assert_eq!(client_a.get_standing_order(&secondly).next_run, 100 + year);
+
+    // Otherwise the next run rounds up to the following boundary
+    e.ledger().with_mut(|li| li.timestamp = 100 + year + 1);
+    client_a.resume_standing_order(&weekly);
+    assert_eq!(
+        client_a.get_standing_order(&weekly).next_run,
+        53 * 7 * 24 * 60 * 60
+    );
+}
+
+#[test]
+fn test_standing_order_skips_runs_missed_by_keeper() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &1_000);
+
+    let day: u64 = 24 * 60 * 60;
+    let id = client_a.create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &100,
+        &day,
+        &day,
+        &None,
+        &None,
+    );
+
+    // Nobody called for a few days: one run is paid and the rest skipped
+    e.ledger().with_mut(|li| li.timestamp = 4 * day + 10);
+    assert_eq!(client_a.execute_due_orders(), vec![&e, id]);
+    assert_eq!(client_a.execute_due_orders().len(), 0);
+    let order = client_a.get_standing_order(&id);
+    assert_eq!(order.runs, 1);
+    assert_eq!(order.next_run, 5 * day);
+    assert_eq!(token_client.balance(&account_b_addr), 100);
+
+    e.ledger().with_mut(|li| li.timestamp = 5 * day);
+    assert_eq!(client_a.execute_due_orders(), vec![&e, id]);
+    assert_eq!(client_a.get_standing_order(&id).next_run, 6 * day);
+}
+
+#[test]
+fn test_standing_order_validation() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (token_client, _) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+    let stranger = Address::generate(&e);
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+
+    let result = client_a.try_create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &0,
+        &60,
+        &0,
+        &None,
+        &None,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidAmount)));
+    let result = client_a.try_create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &10,
+        &0,
+        &0,
+        &None,
+        &None,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidSchedule)));
+    let result = client_a.try_create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &10,
+        &60,
+        &100,
+        &Some(100),
+        &None,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidSchedule)));
+    e.ledger().with_mut(|li| li.timestamp = 100);
+    let result = client_a.try_create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &10,
+        &60,
+        &99,
+        &None,
+        &None,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidSchedule)));
+    let result =
+        client_a.try_create_standing_order(&stranger, &token_address, &10, &60, &100, &None, &None);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
+    let result = client_a.try_get_standing_order(&0);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::OrderNotFound)));
+
+    // An underfunded order stays due instead of failing the keeper call
+    client_a.create_standing_order(
+        &account_b_addr,
+        &token_address,
+        &10,
+        &60,
+        &100,
+        &None,
+        &None,
+    );
+    assert_eq!(client_a.execute_due_orders().len(), 0);
+    assert_eq!(client_a.get_standing_order(&0).runs, 0);
+}
+
+#[test]
+fn test_execute_batch_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, account_c_addr, account_d_addr) =
+        setup_smart_account(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![
+                &e,
+                account_b_addr.clone(),
+                account_c_addr.clone(),
+                account_d_addr.clone(),
+            ],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &1_000);
+
+    let batch = vec![
+        &e,
+        (account_b_addr.clone(), 100),
+        (account_c_addr.clone(), 200),
+        (account_b_addr.clone(), 50),
+    ];
+    let results = client_a.execute_batch_transfer(&batch);
+    assert_last_event(
+        &e,
+        &account_a,
+        BatchTransferExecuted {
+            label: String::from_str(&e, "A"),
+            token: token_address.clone(),
+            count: 3,
+            total: 350,
+        },
+    );
+    assert_eq!(results.len(), 3);
+    assert_eq!(
+        results.get(1).unwrap(),
+        BatchTransferResult {
+            index: 1,
+            to: account_c_addr.clone(),
+            amount: 200,
+            fee: 0,
+            net: 200,
+        }
+    );
+    assert_eq!(token_client.balance(&account_b_addr), 150);
+    assert_eq!(token_client.balance(&account_c_addr), 200);
+    assert_eq!(token_client.balance(&account_a), 650);
+
+    // Each line reports the fee taken out of it
+    let collector = Address::generate(&e);
+    client_a.set_fee_collector(&collector);
+    client_a.set_fee_schedule(
+        &token_address,
+        &None,
+        &Some(FeeSchedule {
+            bps: 100,
+            min_fee: 0,
+        }),
+    );
+    let results = client_a.execute_batch_transfer(&vec![&e, (account_d_addr.clone(), 300)]);
+    assert_eq!(
+        results.get(0).unwrap(),
+        BatchTransferResult {
+            index: 0,
+            to: account_d_addr.clone(),
+            amount: 300,
+            fee: 3,
+            net: 297,
+        }
+    );
+    assert_eq!(token_client.balance(&account_d_addr), 297);
+    assert_eq!(token_client.balance(&collector), 3);
+}
+
+#[test]
+fn test_execute_batch_transfer_is_all_or_nothing() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, account_c_addr, _) = setup_smart_account(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+    let stranger = Address::generate(&e);
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone(), account_c_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &1_000);
+
+    let result = client_a.try_execute_batch_transfer(&vec![
+        &e,
+        (account_b_addr.clone(), 100),
+        (stranger.clone(), 100),
+    ]);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
+
+    let result = client_a.try_execute_batch_transfer(&vec![
+        &e,
+        (account_b_addr.clone(), 100),
+        (account_c_addr.clone(), 0),
+    ]);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidAmount)));
+
+    let result = client_a.try_execute_batch_transfer(&vec![
+        &e,
+        (account_b_addr.clone(), 600),
+        (account_c_addr.clone(), 600),
+    ]);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InsufficientBalance)));
+
+    let result = client_a.try_execute_batch_transfer(&Vec::new(&e));
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidBatch)));
+
+    // A spending limit tripped midway rolls back the lines already paid
+    client_a.set_spending_limits(
+        &token_address,
+        &SpendingLimits {
+            per_transaction: None,
+            per_destination: Some(150),
+            per_account: None,
+        },
+    );
+    let result = client_a.try_execute_batch_transfer(&vec![
+        &e,
+        (account_b_addr.clone(), 100),
+        (account_c_addr.clone(), 100),
+        (account_b_addr.clone(), 100),
+    ]);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::LimitExceeded)));
+    assert_eq!(token_client.balance(&account_b_addr), 0);
+    assert_eq!(token_client.balance(&account_c_addr), 0);
+    assert_eq!(token_client.balance(&account_a), 1_000);
+    assert_eq!(client_a.get_rolling_spend(&token_address, &None), 0);
+}
+
+#[test]
+fn test_payment_reference_is_idempotent() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, account_c_addr, _) = setup_smart_account(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone(), account_c_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &1_000);
+
+    // 1% corridor fee, so the record shows both gross and net
+    client_a.set_fee_collector(&Address::generate(&e));
+    client_a.set_fee_schedule(
+        &token_address,
+        &None,
+        &Some(FeeSchedule {
+            bps: 100,
+            min_fee: 0,
+        }),
+    );
+
+    let reference = BytesN::from_array(&e, &[7; 32]);
+    let memo = Some(String::from_str(&e, "invoice 42"));
+    e.ledger().with_mut(|li| li.timestamp = 5_000);
+    client_a.execute_payment(
+        &token_address,
+        &account_b_addr,
+        &100,
+        &Some(reference.clone()),
+        &memo,
+    );
+    assert_last_event(
+        &e,
+        &account_a,
+        PaymentExecuted {
+            label: String::from_str(&e, "A"),
+            reference: reference.clone(),
+            to: account_b_addr.clone(),
+            amount: 100,
+            fee: 1,
+            net: 99,
+            memo: memo.clone(),
+        },
+    );
+
+    let payment = client_a.get_payment(&reference);
+    assert_eq!(payment.to, account_b_addr);
+    assert_eq!(payment.token, token_address);
+    assert_eq!(payment.amount, 100);
+    assert_eq!(payment.fee, 1);
+    assert_eq!(payment.net, 99);
+    assert_eq!(payment.memo, memo);
+    assert_eq!(payment.timestamp, 5_000);
+
+    // A retry with the same reference is rejected, whatever the payload
+    let result = client_a.try_execute_payment(
+        &token_address,
+        &account_c_addr,
+        &100,
+        &Some(reference.clone()),
+        &None,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::DuplicateReference)));
+    assert_eq!(token_client.balance(&account_b_addr), 99);
+    assert_eq!(token_client.balance(&account_c_addr), 0);
+
+    // Unreferenced payments are not tracked, so they cannot carry a memo
+    client_a.execute_payment(&token_address, &account_c_addr, &50, &None, &None);
+    let unknown = BytesN::from_array(&e, &[8; 32]);
+    let result = client_a.try_get_payment(&unknown);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::PaymentNotFound)));
+    let result = client_a.try_execute_payment(&token_address, &account_c_addr, &50, &None, &memo);
+    assert_eq!(
+        result.err(),
+        Some(Ok(RemittanceError::MemoWithoutReference))
+    );
+
+    let long_memo = Some(String::from_str(&e, &"x".repeat(65)));
+    let result =
+        client_a.try_execute_payment(&token_address, &account_c_addr, &50, &None, &long_memo);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::MemoTooLong)));
+
+    // Once the record expires the reference may be used again
+    e.ledger().with_mut(|li| li.sequence_number += 31 * 17_280);
+    let result = client_a.try_get_payment(&reference);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::PaymentNotFound)));
+    client_a.execute_payment(
+        &token_address,
+        &account_b_addr,
+        &100,
+        &Some(reference),
+        &None,
+    );
+    assert_eq!(token_client.balance(&account_b_addr), 198);
+}
+
+#[test]
+fn test_corridor_fees() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, account_c_addr, _) = setup_smart_account(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+    let collector = Address::generate(&e);
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone(), account_c_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &100_000);
+
+    // 1% with a floor of 5 by default, 2.5% towards account C
+    client_a.set_fee_schedule(
+        &token_address,
+        &None,
+        &Some(FeeSchedule {
+            bps: 100,
+            min_fee: 5,
+        }),
+    );
+    client_a.set_fee_schedule(
+        &token_address,
+        &Some(account_c_addr.clone()),
+        &Some(FeeSchedule {
+            bps: 250,
+            min_fee: 0,
+        }),
+    );
+
+    // Schedules are inert until a collector is set
+    assert_eq!(
+        client_a.quote_fee(&token_address, &account_b_addr, &1_000),
+        0
+    );
+    client_a.execute_transfer(&account_b_addr, &1_000);
+    assert_eq!(token_client.balance(&account_b_addr), 1_000);
+
+    client_a.set_fee_collector(&collector);
+    assert_eq!(
+        client_a.quote_fee(&token_address, &account_b_addr, &1_000),
+        10
+    );
+    assert_eq!(client_a.quote_fee(&token_address, &account_b_addr, &100), 5);
+
+    client_a.execute_transfer(&account_b_addr, &1_000);
+    assert_eq!(token_client.balance(&account_b_addr), 1_990);
+    client_a.execute_transfer(&account_b_addr, &100);
+    assert_eq!(token_client.balance(&account_b_addr), 2_085);
+    client_a.execute_transfer(&account_c_addr, &2_000);
+    assert_last_event(
+        &e,
+        &account_a,
+        TransferExecuted {
+            label: String::from_str(&e, "A"),
+            to: account_c_addr.clone(),
+            token: token_address.clone(),
+            amount: 1_950,
+        },
+    );
+    assert_eq!(token_client.balance(&collector), 65);
+    assert_eq!(client_a.get_fees_collected(&token_address), 65);
+
+    // The fee may not swallow the whole payout
+    let result = client_a.try_execute_transfer(&account_b_addr, &5);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidAmount)));
+
+    // Removing the override falls back to the default schedule
+    client_a.set_fee_schedule(&token_address, &Some(account_c_addr.clone()), &None);
+    assert_eq!(
+        client_a.get_fee_schedule(&token_address, &Some(account_c_addr.clone())),
+        None
+    );
+    assert_eq!(
+        client_a.quote_fee(&token_address, &account_c_addr, &2_000),
+        20
+    );
+
+    // Schedules are per token: another token pays nothing until it has one,
+    // then its own floor applies
+    let (other_client, other_admin_client) = create_token_contract(&e, &token_admin);
+    let other = other_client.address.clone();
+    client_a.add_supported_token(&other);
+    other_admin_client.mint(&account_a, &100_000);
+    assert_eq!(client_a.quote_fee(&other, &account_b_addr, &1_000), 0);
+    client_a.execute_token_transfer(&other, &account_b_addr, &1_000);
+    assert_eq!(other_client.balance(&account_b_addr), 1_000);
+    client_a.set_fee_schedule(
+        &other,
+        &None,
+        &Some(FeeSchedule {
+            bps: 0,
+            min_fee: 50,
+        }),
+    );
+    assert_eq!(client_a.quote_fee(&other, &account_b_addr, &1_000), 50);
+    assert_eq!(client_a.quote_fee(&token_address, &account_b_addr, &100), 5);
+    client_a.execute_token_transfer(&other, &account_b_addr, &1_000);
+    assert_eq!(other_client.balance(&collector), 50);
+    assert_eq!(client_a.get_fees_collected(&other), 50);
+
+    let result = client_a.try_set_fee_schedule(
+        &token_address,
+        &None,
+        &Some(FeeSchedule {
+            bps: 10_001,
+            min_fee: 0,
+        }),
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidAmount)));
+}
+
+#[test]
+fn test_failing_standing_order_does_not_consume_limits() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    token_admin_client.mint(&account_a, &1_000);
+
+    client_a.set_fee_collector(&Address::generate(&e));
+    client_a.set_fee_schedule(
+        &token_address,
+        &None,
+        &Some(FeeSchedule { bps: 0, min_fee: 5 }),
+    );
+    client_a.set_spending_limits(
+        &token_address,
+        &SpendingLimits {
+            per_transaction: None,
+            per_destination: Some(100),
+            per_account: Some(100),
+        },
+    );
+
+    // The minimum fee swallows every run of this order, so it always fails
+    let id =
+        client_a.create_standing_order(&account_b_addr, &token_address, &5, &1, &0, &None, &None);
+    e.set_auths(&[]);
+    for second in 0..30 {
+        e.ledger().with_mut(|li| li.timestamp = second);
+        assert_eq!(client_a.execute_due_orders().len(), 0);
+    }
+    assert_eq!(client_a.get_standing_order(&id).runs, 0);
+    assert_eq!(client_a.get_rolling_spend(&token_address, &None), 0);
+    assert_eq!(
+        client_a.get_rolling_spend(&token_address, &Some(account_b_addr.clone())),
+        0
+    );
+
+    e.mock_all_auths();
+    client_a.execute_transfer(&account_b_addr, &100);
+    assert_eq!(token_client.balance(&account_b_addr), 95);
+}
+
+#[test]
+fn test_corridor_fee_on_forex_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+    let collector = Address::generate(&e);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+    usdc_admin_client.mint(&account_a, &1_000);
+    eurc_admin_client.mint(&account_a, &1_000);
+
+    client_a.set_fee_collector(&collector);
+    client_a.set_fee_schedule(
+        &usdc_client.address,
+        &None,
+        &Some(FeeSchedule {
+            bps: 100,
+            min_fee: 0,
+        }),
+    );
+
+    // The fee is paid in the input token and only the rest is swapped
+    client_a.execute_forex_transfer(&account_b_addr, &500, &495, &600, &true);
+    assert_eq!(usdc_client.balance(&collector), 5);
+    assert_eq!(eurc_client.balance(&account_b_addr), 495);
+    assert_eq!(client_a.get_fees_collected(&usdc_client.address), 5);
+}
+
+#[test]
+fn test_execute_forex_path_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+    let xlm = Address::generate(&e);
+    let btc = Address::generate(&e);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+    usdc_admin_client.mint(&account_a, &1_000);
+    eurc_admin_client.mint(&account_a, &1_000);
+
+    let deadline: u64 = 600;
+    let via_xlm = vec![
+        &e,
+        usdc_client.address.clone(),
+        xlm.clone(),
+        eurc_client.address.clone(),
+    ];
+
+    // Intermediates must be approved one way or another
+    let result =
+        client_a.try_execute_forex_path_transfer(&account_b_addr, &100, &100, &deadline, &via_xlm);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
+
+    // An approved route is accepted as a whole
+    client_a.add_forex_route(&via_xlm);
+    assert_eq!(client_a.get_forex_routes(), vec![&e, via_xlm.clone()]);
+    client_a.execute_forex_path_transfer(&account_b_addr, &100, &100, &deadline, &via_xlm);
+    assert_last_event(
+        &e,
+        &account_a,
+        ForexTransferExecuted {
+            label: String::from_str(&e, "A"),
+            to: account_b_addr.clone(),
+            token: eurc_client.address.clone(),
+            amount: 100,
+            amount_out: 100,
+            path: via_xlm.clone(),
+            venue: router.clone(),
+        },
+    );
+    assert_eq!(eurc_client.balance(&account_b_addr), 100);
+
+    // Otherwise every hop must be an allowed intermediate
+    client_a.remove_forex_route(&via_xlm);
+    client_a.set_forex_intermediates(&vec![&e, xlm.clone()]);
+    let reverse = vec![
+        &e,
+        eurc_client.address.clone(),
+        xlm.clone(),
+        usdc_client.address.clone(),
+    ];
+    client_a.execute_forex_path_transfer(&account_b_addr, &200, &200, &deadline, &reverse);
+    assert_eq!(usdc_client.balance(&account_b_addr), 200);
+    let via_btc = vec![
+        &e,
+        usdc_client.address.clone(),
+        xlm.clone(),
+        btc.clone(),
+        eurc_client.address.clone(),
+    ];
+    let result =
+        client_a.try_execute_forex_path_transfer(&account_b_addr, &100, &100, &deadline, &via_btc);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::NotAllowed)));
+
+    // Malformed paths
+    let ends_in_xlm = vec![&e, usdc_client.address.clone(), xlm.clone()];
+    let result = client_a.try_execute_forex_path_transfer(
+        &account_b_addr,
+        &100,
+        &100,
+        &deadline,
+        &ends_in_xlm,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidPath)));
+    let repeated = vec![
+        &e,
+        usdc_client.address.clone(),
+        xlm.clone(),
+        usdc_client.address.clone(),
+    ];
+    let result =
+        client_a.try_execute_forex_path_transfer(&account_b_addr, &100, &100, &deadline, &repeated);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidPath)));
+    let result = client_a.try_add_forex_route(&vec![&e, usdc_client.address.clone()]);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidPath)));
+}
+
+#[test]
+fn test_oracle_floor_on_forex_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+    let oracle = e.register(MockOracle, ());
+    let oracle_client = MockOracleClient::new(&e, &oracle);
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    usdc_admin_client.mint(&account_a, &10_000);
+    eurc_admin_client.mint(&account_a, &10_000);
+
+    // 1 EURC = 1.25 USDC, at most 1% below the oracle rate, 5 minutes old
+    e.ledger().with_mut(|li| li.timestamp = 10_000);
+    oracle_client.set_price(&usdc_client.address, &100_000_000_000_000, &10_000);
+    oracle_client.set_price(&eurc_client.address, &125_000_000_000_000, &10_000);
+    let config = OracleConfig {
+        address: oracle.clone(),
+        max_deviation_bps: 100,
+        max_age: 300,
+    };
+    client_a.configure_forex(&router, &eurc_client.address, &Some(config.clone()));
+    assert_eq!(client_a.get_oracle_config(), Some(config));
+
+    // 1000 USDC is worth 800 EURC, so the floor is 792
+    let deadline: u64 = 20_000;
+    let result = client_a.try_execute_forex_transfer(&account_b_addr, &1_000, &1, &deadline, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SlippageTooHigh)));
+    let result =
+        client_a.try_execute_forex_transfer(&account_b_addr, &1_000, &791, &deadline, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SlippageTooHigh)));
+    client_a.execute_forex_transfer(&account_b_addr, &1_000, &792, &deadline, &true);
+
+    // Stale prices block swaps instead of being trusted
+    e.ledger().with_mut(|li| li.timestamp = 10_301);
+    let result =
+        client_a.try_execute_forex_transfer(&account_b_addr, &1_000, &800, &deadline, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::OracleStale)));
+
+    // Missing prices too
+    oracle_client.set_price(&usdc_client.address, &100_000_000_000_000, &10_301);
+    let unpriced = Address::generate(&e);
+    client_a.add_supported_token(&unpriced);
+    let path = vec![&e, usdc_client.address.clone(), unpriced];
+    let result =
+        client_a.try_execute_forex_path_transfer(&account_b_addr, &1_000, &800, &deadline, &path);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::OracleUnavailable)));
+
+    // Removing the oracle falls back to the caller's minimum
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+    client_a.execute_forex_transfer(&account_b_addr, &1_000, &1, &deadline, &true);
+}
+
+#[test]
+fn test_forex_transfer_routes_through_best_adapter() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+    usdc_admin_client.mint(&account_a, &10_000);
+
+    // Three venues paying 0.90, 0.93 and 0.91 EURC per USDC
+    let mut venues = Vec::new(&e);
+    for rate_bps in [9_000u32, 9_300, 9_100] {
+        let adapter = e.register(MockAdapter, (rate_bps,));
+        eurc_admin_client.mint(&adapter, &10_000);
+        client_a.add_swap_adapter(&adapter);
+        venues.push_back(adapter);
+    }
+    // A venue that cannot quote is ignored
+    let broken = e.register(MockAdapter, (0u32,));
+    client_a.add_swap_adapter(&broken);
+    assert_eq!(client_a.get_swap_adapters().len(), 4);
+
+    let deadline: u64 = 600;
+    let path = vec![&e, usdc_client.address.clone(), eurc_client.address.clone()];
+    client_a.execute_forex_transfer(&account_b_addr, &1_000, &920, &deadline, &true);
+    assert_last_event(
+        &e,
+        &account_a,
+        ForexTransferExecuted {
+            label: String::from_str(&e, "A"),
+            to: account_b_addr.clone(),
+            token: eurc_client.address.clone(),
+            amount: 1_000,
+            amount_out: 930,
+            path,
+            venue: venues.get(1).unwrap(),
+        },
+    );
+    assert_eq!(eurc_client.balance(&account_b_addr), 930);
+    assert_eq!(usdc_client.balance(&venues.get(1).unwrap()), 1_000);
+    assert_eq!(usdc_client.balance(&account_a), 9_000);
+
+    // Once the best venue is gone the next best one fills the order
+    client_a.remove_swap_adapter(&venues.get(1).unwrap());
+    client_a.execute_forex_transfer(&account_b_addr, &1_000, &910, &deadline, &true);
+    assert_eq!(eurc_client.balance(&account_b_addr), 1_840);
+    assert_eq!(usdc_client.balance(&venues.get(2).unwrap()), 1_000);
+
+    // No venue can meet the minimum
+    let result =
+        client_a.try_execute_forex_transfer(&account_b_addr, &1_000, &950, &deadline, &true);
+    assert!(result.is_err());
+    assert_eq!(usdc_client.balance(&account_a), 8_000);
+}
+
+#[test]
+fn test_execute_forex_transfer_exact_out() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+    let collector = Address::generate(&e);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+    usdc_admin_client.mint(&account_a, &1_000);
+    eurc_admin_client.mint(&account_a, &1_000);
+
+    // The recipient gets exactly what was asked; only the input used is reported
+    let deadline: u64 = 600;
+    let spent =
+        client_a.execute_forex_transfer_exact_out(&account_b_addr, &400, &500, &deadline, &true);
+    assert_eq!(spent, 400);
+    assert_last_event(
+        &e,
+        &account_a,
+        ForexTransferExecuted {
+            label: String::from_str(&e, "A"),
+            to: account_b_addr.clone(),
+            token: eurc_client.address.clone(),
+            amount: 400,
+            amount_out: 400,
+            path: vec![&e, usdc_client.address.clone(), eurc_client.address.clone()],
+            venue: router.clone(),
+        },
+    );
+    assert_eq!(eurc_client.balance(&account_b_addr), 400);
+
+    // The ceiling is enforced by the router
+    let result = client_a.try_execute_forex_transfer_exact_out(
+        &account_b_addr,
+        &400,
+        &300,
+        &deadline,
+        &true,
+    );
+    assert!(result.is_err());
+
+    // Fees come on top of the input and count towards the limits
+    client_a.set_fee_collector(&collector);
+    client_a.set_fee_schedule(
+        &usdc_client.address,
+        &None,
+        &Some(FeeSchedule {
+            bps: 100,
+            min_fee: 0,
+        }),
+    );
+    client_a.set_spending_limits(
+        &usdc_client.address,
+        &SpendingLimits {
+            per_transaction: Some(200),
+            per_destination: None,
+            per_account: None,
+        },
+    );
+    let result = client_a.try_execute_forex_transfer_exact_out(
+        &account_b_addr,
+        &200,
+        &300,
+        &deadline,
+        &true,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::LimitExceeded)));
+    // The ceiling also covers the fee
+    let result = client_a.try_execute_forex_transfer_exact_out(
+        &account_b_addr,
+        &100,
+        &100,
+        &deadline,
+        &true,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SlippageTooHigh)));
+    client_a.execute_forex_transfer_exact_out(&account_b_addr, &100, &101, &deadline, &true);
+    assert_eq!(usdc_client.balance(&collector), 1);
+    assert_eq!(eurc_client.balance(&account_b_addr), 500);
+}
+
+#[test]
+fn test_quote_forex() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+    let collector = Address::generate(&e);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+    let oracle = e.register(MockOracle, ());
+    let oracle_client = MockOracleClient::new(&e, &oracle);
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+    usdc_admin_client.mint(&account_a, &10_000);
+    eurc_admin_client.mint(&account_a, &10_000);
+    client_a.set_fee_collector(&collector);
+    client_a.set_fee_schedule(
+        &usdc_client.address,
+        &None,
+        &Some(FeeSchedule {
+            bps: 100,
+            min_fee: 0,
+        }),
+    );
+
+    let path = vec![&e, usdc_client.address.clone(), eurc_client.address.clone()];
+    let quote = client_a.quote_forex(&account_b_addr, &1_000, &true);
+    assert_eq!(
+        quote,
+        ForexQuote {
+            amount_in: 1_000,
+            fee: 10,
+            amount_out: 990,
+            min_amount_out: None,
+            path: path.clone(),
+            venue: router.clone(),
+        }
+    );
+
+    // The quote matches what the transfer then delivers
+    client_a.execute_forex_transfer(&account_b_addr, &1_000, &quote.amount_out, &600, &true);
+    assert_eq!(eurc_client.balance(&account_b_addr), 990);
+
+    let quote = client_a.quote_forex_exact_out(&account_b_addr, &500, &true);
+    assert_eq!(quote.amount_in, 505);
+    assert_eq!(quote.fee, 5);
+    assert_eq!(quote.amount_out, 500);
+
+    // With an oracle the floor is reported, and quotes below it are refused
+    oracle_client.set_price(&usdc_client.address, &100, &0);
+    oracle_client.set_price(&eurc_client.address, &100, &0);
+    client_a.configure_forex(
+        &router,
+        &eurc_client.address,
+        &Some(OracleConfig {
+            address: oracle.clone(),
+            max_deviation_bps: 50,
+            max_age: 300,
+        }),
+    );
+    let quote = client_a.quote_forex(&account_b_addr, &1_000, &true);
+    assert_eq!(quote.min_amount_out, Some(985));
+    oracle_client.set_price(&eurc_client.address, &90, &0);
+    let result = client_a.try_quote_forex(&account_b_addr, &1_000, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SlippageTooHigh)));
+    let result = client_a.try_quote_forex_exact_out(&account_b_addr, &500, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SlippageTooHigh)));
+}
+
+#[test]
+fn test_forex_transfer_rejects_hostile_router() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    usdc_admin_client.mint(&account_a, &1_000);
+    eurc_admin_client.mint(&account_a, &1_000);
+
+    // Reports the full amount but delivers half; the account's own EURC must
+    // not be used to make up the difference
+    let skimming = e.register(HostileRouter, (5_000i128, 10_000i128));
+    eurc_admin_client.mint(&skimming, &1_000);
+    client_a.configure_forex(&skimming, &eurc_client.address, &None);
+    let deadline: u64 = 600;
+    let result = client_a.try_execute_forex_transfer(&account_b_addr, &200, &100, &deadline, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SwapFailed)));
+    let result = client_a.try_execute_forex_transfer_exact_out(
+        &account_b_addr,
+        &200,
+        &200,
+        &deadline,
+        &true,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SwapFailed)));
+
+    // Honestly reports delivering less than the minimum it was given
+    let short = e.register(HostileRouter, (5_000i128, 5_000i128));
+    eurc_admin_client.mint(&short, &1_000);
+    client_a.configure_forex(&short, &eurc_client.address, &None);
+    let result = client_a.try_execute_forex_transfer(&account_b_addr, &200, &150, &deadline, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SwapFailed)));
+    let result = client_a.try_execute_forex_transfer_exact_out(
+        &account_b_addr,
+        &200,
+        &200,
+        &deadline,
+        &true,
+    );
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SwapFailed)));
+
+    assert_eq!(eurc_client.balance(&account_b_addr), 0);
+    assert_eq!(usdc_client.balance(&account_a), 1_000);
+    assert_eq!(eurc_client.balance(&account_a), 1_000);
+}
+
+#[test]
+fn test_swap_holdings() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, _, _) = setup_smart_account(&e);
+    let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
+    let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
+
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
+    let oracle = e.register(MockOracle, ());
+    let oracle_client = MockOracleClient::new(&e, &oracle);
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            vec![&e, account_b_addr.clone()],
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
+    usdc_admin_client.mint(&account_a, &1_000);
+
+    let deadline: u64 = 600;
+    let amount_out = client_a.swap_holdings(&400, &400, &deadline, &true);
+    assert_eq!(amount_out, 400);
+    assert_last_event(
+        &e,
+        &account_a,
+        HoldingsSwapped {
+            label: String::from_str(&e, "A"),
+            token_in: usdc_client.address.clone(),
+            token_out: eurc_client.address.clone(),
+            amount_in: 400,
+            amount_out: 400,
+            venue: router.clone(),
+        },
+    );
+    assert_eq!(
+        e.auths().first().unwrap().0,
+        admin,
+        "swap_holdings must be authorized by the admin"
+    );
+    assert_eq!(usdc_client.balance(&account_a), 600);
+    assert_eq!(eurc_client.balance(&account_a), 400);
+
+    // And back again
+    client_a.swap_holdings(&100, &100, &deadline, &false);
+    assert_eq!(usdc_client.balance(&account_a), 700);
+    assert_eq!(eurc_client.balance(&account_a), 300);
+
+    // Same slippage guard as transfers
+    oracle_client.set_price(&usdc_client.address, &100, &0);
+    oracle_client.set_price(&eurc_client.address, &100, &0);
+    client_a.configure_forex(
+        &router,
+        &eurc_client.address,
+        &Some(OracleConfig {
+            address: oracle,
+            max_deviation_bps: 100,
+            max_age: 300,
+        }),
+    );
+    let result = client_a.try_swap_holdings(&100, &1, &deadline, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::SlippageTooHigh)));
+    let result = client_a.try_swap_holdings(&0, &1, &deadline, &true);
+    assert_eq!(result.err(), Some(Ok(RemittanceError::InvalidAmount)));
+}
+
+#[test]
+fn test_multiple_transfers_between_accounts() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, _, _, _) = setup_smart_account(&e);
+
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    // Create 4 smart accounts; each only learns the others' addresses once
+    // all are deployed, as in the deploy scripts
+    let mut accounts = Vec::new(&e);
+    for label in ["A", "B", "C", "D"] {
+        let account = e.register(
+            RemittanceAccount,
+            (
+                admin.clone(),
+                token_address.clone(),
+                Vec::<Address>::new(&e),
+                String::from_str(&e, label),
+            ),
+        );
+        accounts.push_back(account);
+    }
+    let account_a = accounts.get_unchecked(0);
+    let account_b = accounts.get_unchecked(1);
+    let account_c = accounts.get_unchecked(2);
+    let account_d = accounts.get_unchecked(3);
+
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    let client_b = RemittanceAccountClient::new(&e, &account_b);
+    let client_c = RemittanceAccountClient::new(&e, &account_c);
+    let client_d = RemittanceAccountClient::new(&e, &account_d);
+
+    // Point each account at the others
+    client_a.update_destinations(&vec![
+        &e,
+        account_b.clone(),
+        account_c.clone(),
+        account_d.clone(),
+    ]);
+    client_b.update_destinations(&vec![
+        &e,
+        account_a.clone(),
+        account_c.clone(),
+        account_d.clone(),
+    ]);
+    client_c.update_destinations(&vec![
+        &e,
+        account_a.clone(),
+        account_b.clone(),
+        account_d.clone(),
+    ]);
+    client_d.update_destinations(&vec![
+        &e,
+        account_a.clone(),
+        account_b.clone(),
+        account_c.clone(),
+    ]);
+
+    // Mint initial balances
+    token_admin_client.mint(&account_a, &1000);
+    token_admin_client.mint(&account_b, &1000);
+    token_admin_client.mint(&account_c, &1000);
+    token_admin_client.mint(&account_d, &1000);
+
+    // A -> B: 100
+    client_a.execute_transfer(&account_b, &100);
+    assert_eq!(token_client.balance(&account_a), 900);
+    assert_eq!(token_client.balance(&account_b), 1100);
+
+    // B -> C: 200
+    client_b.execute_transfer(&account_c, &200);
+    assert_eq!(token_client.balance(&account_b), 900);
+    assert_eq!(token_client.balance(&account_c), 1200);
+
+    // C -> D: 150
+    client_c.execute_transfer(&account_d, &150);
+    assert_eq!(token_client.balance(&account_c), 1050);
+    assert_eq!(token_client.balance(&account_d), 1150);
+
+    // D -> A: 250
+    client_d.execute_transfer(&account_a, &250);
+    assert_eq!(token_client.balance(&account_d), 900);
+    assert_eq!(token_client.balance(&account_a), 1150);
+
+    // Verify total supply unchanged
+    let total = token_client.balance(&account_a)
+        + token_client.balance(&account_b)
+        + token_client.balance(&account_c)
+        + token_client.balance(&account_d);
+    assert_eq!(total, 4000);
+}
+
+#[test]
+fn test_get_context_rules() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _account_a_addr, account_b_addr, account_c_addr, account_d_addr) =
+        setup_smart_account(&e);
+
+    let (token_client, _) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    // Register and initialize account A
+    let destinations = vec![
+        &e,
+        account_b_addr.clone(),
+        account_c_addr.clone(),
+        account_d_addr.clone(),
+    ];
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
+    );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+
+    // Get default context rules (should have 1 from init)
+    let rules = client_a.get_context_rules(&ContextRuleType::Default);
+    assert_eq!(rules.len(), 1);
+
+    // Get the first rule
+    let rule = client_a.get_context_rule(&0);
+    assert_eq!(rule.name, String::from_str(&e, "Admin Rule"));
+}
+
+#[test]
+fn test_zero_amount_transfer() {
     let e = Env::default();
     e.mock_all_auths();
 
@@ -437,21 +2504,63 @@ fn test_transfer_more_than_balance_fails() {
     let token_address = token_client.address.clone();
 
     // Register and initialize account A
-    let account_a = e.register(RemittanceAccount, ());
+    let destinations = vec![
+        &e,
+        account_b_addr.clone(),
+        account_c_addr.clone(),
+        account_d_addr.clone(),
+    ];
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
+    );
     let client_a = RemittanceAccountClient::new(&e, &account_a);
 
+    // Mint tokens
+    token_admin_client.mint(&account_a, &1000);
+
+    // Transfer 0 amount (should succeed but not move anything)
+    let result = client_a.try_execute_transfer(&account_b_addr, &0);
+    assert!(result.is_ok());
+
+    // Verify balances unchanged
+    assert_eq!(token_client.balance(&account_a), 1000);
+    assert_eq!(token_client.balance(&account_b_addr), 0);
+}
+
+#[test]
+fn test_transfer_more_than_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (admin, token_admin, _, account_b_addr, account_c_addr, account_d_addr) =
+        setup_smart_account(&e);
+
+    let (token_client, token_admin_client) = create_token_contract(&e, &token_admin);
+    let token_address = token_client.address.clone();
+
+    // Register and initialize account A
     let destinations = vec![
         &e,
         account_b_addr.clone(),
         account_c_addr.clone(),
         account_d_addr.clone(),
     ];
-    client_a.init(
-        &admin,
-        &token_address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            token_address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
 
     // Mint only 100 tokens
     token_admin_client.mint(&account_a, &100);
@@ -474,18 +2583,20 @@ fn test_execute_forex_transfer_primary_to_counter() {
     let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
     let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
 
-    let account_a = e.register(RemittanceAccount, ());
-    let router = e.register(MockRouter, ());
-    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
 
     let destinations = vec![&e, account_b_addr.clone()];
-    client_a.init(
-        &admin,
-        &usdc_client.address,
-        &destinations,
-        &String::from_str(&e, "A"),
+    let account_a = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "A"),
+        ),
     );
-    client_a.configure_forex(&router, &eurc_client.address);
+    let client_a = RemittanceAccountClient::new(&e, &account_a);
+    client_a.configure_forex(&router, &eurc_client.address, &None);
 
     usdc_admin_client.mint(&account_a, &1_000);
     eurc_admin_client.mint(&account_a, &1_000);
@@ -494,8 +2605,24 @@ fn test_execute_forex_transfer_primary_to_counter() {
     let result = client_a.try_execute_forex_transfer(&account_b_addr, &200, &200, &deadline, &true);
     assert!(result.is_ok());
 
+    assert_last_event(
+        &e,
+        &account_a,
+        ForexTransferExecuted {
+            label: String::from_str(&e, "A"),
+            to: account_b_addr.clone(),
+            token: eurc_client.address.clone(),
+            amount: 200,
+            amount_out: 200,
+            path: vec![&e, usdc_client.address.clone(), eurc_client.address.clone()],
+            venue: router.clone(),
+        },
+    );
+
+    // The input left the account; the output was delivered from the swap
     assert_eq!(eurc_client.balance(&account_b_addr), 200);
-    assert_eq!(eurc_client.balance(&account_a), 800);
+    assert_eq!(usdc_client.balance(&account_a), 800);
+    assert_eq!(eurc_client.balance(&account_a), 1_000);
 }
 
 #[test]
@@ -508,18 +2635,20 @@ fn test_execute_forex_transfer_counter_to_primary() {
     let (usdc_client, usdc_admin_client) = create_token_contract(&e, &token_admin);
     let (eurc_client, eurc_admin_client) = create_token_contract(&e, &token_admin);
 
-    let account_b = e.register(RemittanceAccount, ());
-    let router = e.register(MockRouter, ());
-    let client_b = RemittanceAccountClient::new(&e, &account_b);
+    let router = create_mock_router(&e, &usdc_admin_client, &eurc_admin_client);
 
     let destinations = vec![&e, account_a_addr.clone()];
-    client_b.init(
-        &admin,
-        &usdc_client.address,
-        &destinations,
-        &String::from_str(&e, "B"),
+    let account_b = e.register(
+        RemittanceAccount,
+        (
+            admin.clone(),
+            usdc_client.address.clone(),
+            destinations.clone(),
+            String::from_str(&e, "B"),
+        ),
     );
-    client_b.configure_forex(&router, &eurc_client.address);
+    let client_b = RemittanceAccountClient::new(&e, &account_b);
+    client_b.configure_forex(&router, &eurc_client.address, &None);
 
     // Provide balances for both assets
     eurc_admin_client.mint(&account_b, &1_000);
@@ -531,29 +2660,231 @@ fn test_execute_forex_transfer_counter_to_primary() {
     assert!(result.is_ok());
 
     assert_eq!(usdc_client.balance(&account_a_addr), 300);
-    assert_eq!(usdc_client.balance(&account_b), 700);
+    assert_eq!(eurc_client.balance(&account_b), 700);
+    assert_eq!(usdc_client.balance(&account_b), 1_000);
 }
 
+// Router swapping 1:1 at every hop: pulls the input from `to` and pays the
+// output out of its own reserves, so the account sees real balance changes.
 #[contract]
 struct MockRouter;
 
 #[contractimpl]
 impl MockRouter {
+    pub fn router_get_amounts_out(env: Env, amount_in: i128, path: Vec<Address>) -> Vec<i128> {
+        let mut amounts = Vec::new(&env);
+        for _ in path.iter() {
+            amounts.push_back(amount_in);
+        }
+        amounts
+    }
+
+    pub fn router_get_amounts_in(env: Env, amount_out: i128, path: Vec<Address>) -> Vec<i128> {
+        Self::router_get_amounts_out(env, amount_out, path)
+    }
+
+    // Every pool is the router itself
+    pub fn router_pair_for(env: Env, _token_a: Address, _token_b: Address) -> Address {
+        env.current_contract_address()
+    }
+
     pub fn swap_exact_tokens_for_tokens(
         env: Env,
         amount_in: i128,
         amount_out_min: i128,
         path: Vec<Address>,
-        _to: Address,
+        to: Address,
         _deadline: u64,
     ) -> Vec<i128> {
         if path.len() < 2 || amount_in < amount_out_min {
             panic!("invalid swap");
         }
+        settle(&env, &path, &to, amount_in, amount_in);
+        Self::router_get_amounts_out(env, amount_in, path)
+    }
 
-        let mut amounts = Vec::new(&env);
-        amounts.push_back(amount_in);
-        amounts.push_back(amount_in);
-        amounts
+    pub fn swap_tokens_for_exact_tokens(
+        env: Env,
+        amount_out: i128,
+        amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        _deadline: u64,
+    ) -> Vec<i128> {
+        if path.len() < 2 || amount_out > amount_in_max {
+            panic!("invalid swap");
+        }
+        settle(&env, &path, &to, amount_out, amount_out);
+        Self::router_get_amounts_in(env, amount_out, path)
+    }
+}
+
+fn settle(env: &Env, path: &Vec<Address>, to: &Address, amount_in: i128, amount_out: i128) {
+    TokenClient::new(env, &path.first().unwrap()).transfer(
+        to,
+        env.current_contract_address(),
+        &amount_in,
+    );
+    if amount_out > 0 {
+        TokenClient::new(env, &path.last().unwrap()).transfer(
+            &env.current_contract_address(),
+            to,
+            &amount_out,
+        );
+    }
+}
+
+// Router that takes the full input but delivers `delivered_bps` of it and
+// reports `reported_bps` of it as the output.
+#[contract]
+struct HostileRouter;
+
+#[contractimpl]
+impl HostileRouter {
+    pub fn __constructor(env: Env, delivered_bps: i128, reported_bps: i128) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("rates"), &(delivered_bps, reported_bps));
+    }
+
+    pub fn router_get_amounts_in(env: Env, amount_out: i128, _path: Vec<Address>) -> Vec<i128> {
+        vec![&env, amount_out, amount_out]
+    }
+
+    pub fn router_pair_for(env: Env, _token_a: Address, _token_b: Address) -> Address {
+        env.current_contract_address()
+    }
+
+    pub fn swap_exact_tokens_for_tokens(
+        env: Env,
+        amount_in: i128,
+        _amount_out_min: i128,
+        path: Vec<Address>,
+        to: Address,
+        _deadline: u64,
+    ) -> Vec<i128> {
+        let (delivered_bps, reported_bps): (i128, i128) = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("rates"))
+            .unwrap();
+        settle(
+            &env,
+            &path,
+            &to,
+            amount_in,
+            amount_in * delivered_bps / 10_000,
+        );
+        vec![&env, amount_in, amount_in * reported_bps / 10_000]
+    }
+
+    pub fn swap_tokens_for_exact_tokens(
+        env: Env,
+        amount_out: i128,
+        _amount_in_max: i128,
+        path: Vec<Address>,
+        to: Address,
+        _deadline: u64,
+    ) -> Vec<i128> {
+        let (delivered_bps, reported_bps): (i128, i128) = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("rates"))
+            .unwrap();
+        settle(
+            &env,
+            &path,
+            &to,
+            amount_out,
+            amount_out * delivered_bps / 10_000,
+        );
+        vec![&env, amount_out, amount_out * reported_bps / 10_000]
+    }
+}
+
+#[contract]
+struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn set_price(env: Env, token: Address, price: i128, timestamp: u64) {
+        env.storage()
+            .instance()
+            .set(&token, &oracle::PriceData { price, timestamp });
+    }
+
+    pub fn lastprice(env: Env, asset: oracle::Asset) -> Option<oracle::PriceData> {
+        match asset {
+            oracle::Asset::Stellar(token) => env.storage().instance().get(&token),
+            oracle::Asset::Other(_) => None,
+        }
+    }
+}
+
+// Swap adapter filling every order at a fixed rate out of its own balance
+#[contract]
+struct MockAdapter;
+
+#[contractimpl]
+impl MockAdapter {
+    pub fn __constructor(env: Env, rate_bps: u32) {
+        env.storage()
+            .instance()
+            .set(&symbol_short!("rate"), &rate_bps);
+    }
+
+    pub fn quote(env: Env, amount_in: i128, _path: Vec<Address>) -> i128 {
+        let rate_bps: u32 = env
+            .storage()
+            .instance()
+            .get(&symbol_short!("rate"))
+            .unwrap();
+        if rate_bps == 0 {
+            panic!("no liquidity");
+        }
+        amount_in * rate_bps as i128 / 10_000
+    }
+
+    pub fn swap(
+        env: Env,
+        amount_in: i128,
+        min_amount_out: i128,
+        path: Vec<Address>,
+        to: Address,
+        _deadline: u64,
+    ) -> i128 {
+        let amount_out = Self::quote(env.clone(), amount_in, path.clone());
+        if amount_out < min_amount_out {
+            panic!("insufficient output");
+        }
+        TokenClient::new(&env, &path.last().unwrap()).transfer(
+            &env.current_contract_address(),
+            &to,
+            &amount_out,
+        );
+        amount_out
+    }
+}
+
+// Treasury that only checks the signer's authorization, as the real one does
+#[contract]
+struct MockTreasury;
+
+#[contractimpl]
+impl MockTreasury {
+    pub fn propose_withdraw(
+        _env: Env,
+        signer: Address,
+        _token: Address,
+        _to: Address,
+        _amount: i128,
+    ) -> u32 {
+        signer.require_auth();
+        0
+    }
+
+    pub fn approve_withdraw(_env: Env, signer: Address, _request_id: u32) -> bool {
+        signer.require_auth();
+        true
     }
 }
@@ -0,0 +1,111 @@
+#![no_std]
+
+//! Weighted threshold policy for OpenZeppelin smart-account context rules.
+//!
+//! Each signer on the rule carries a weight, and a context is authorized
+//! once the weights of the signers present reach `threshold`. Useful when
+//! some corridor accounts (or an operations key) should count for more than
+//! others in a treasury quorum.
+//!
+//! One deployment can serve any number of smart accounts; weights and
+//! thresholds are stored per `(smart_account, context_rule_id)`.
+
+use soroban_sdk::auth::Context;
+use soroban_sdk::{contract, contractimpl, Address, Env, Map, Vec};
+use stellar_accounts::policies::{weighted_threshold, Policy};
+use stellar_accounts::smart_account::{ContextRule, Signer};
+
+pub use stellar_accounts::policies::weighted_threshold::{
+    WeightedThresholdAccountParams, WeightedThresholdError,
+};
+
+#[contract]
+pub struct WeightedThresholdPolicy;
+
+#[contractimpl]
+impl WeightedThresholdPolicy {
+    /// Change the threshold of an installed rule; must be authorized by the account
+    pub fn set_threshold(
+        env: Env,
+        threshold: u32,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        weighted_threshold::set_threshold(&env, threshold, &context_rule, &smart_account);
+    }
+
+    /// Change one signer's weight; must be authorized by the account
+    pub fn set_signer_weight(
+        env: Env,
+        signer: Signer,
+        weight: u32,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        weighted_threshold::set_signer_weight(&env, &signer, weight, &context_rule, &smart_account);
+    }
+
+    /// Threshold configured for an installed rule
+    pub fn get_threshold(env: Env, context_rule_id: u32, smart_account: Address) -> u32 {
+        weighted_threshold::get_threshold(&env, context_rule_id, &smart_account)
+    }
+
+    /// Signer weights configured for an installed rule
+    pub fn get_signer_weights(
+        env: Env,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> Map<Signer, u32> {
+        weighted_threshold::get_signer_weights(&env, &context_rule, &smart_account)
+    }
+}
+
+#[contractimpl]
+impl Policy for WeightedThresholdPolicy {
+    type AccountParams = WeightedThresholdAccountParams;
+
+    fn can_enforce(
+        env: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) -> bool {
+        weighted_threshold::can_enforce(
+            env,
+            &context,
+            &authenticated_signers,
+            &context_rule,
+            &smart_account,
+        )
+    }
+
+    fn enforce(
+        env: &Env,
+        context: Context,
+        authenticated_signers: Vec<Signer>,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        weighted_threshold::enforce(
+            env,
+            &context,
+            &authenticated_signers,
+            &context_rule,
+            &smart_account,
+        );
+    }
+
+    fn install(
+        env: &Env,
+        install_params: WeightedThresholdAccountParams,
+        context_rule: ContextRule,
+        smart_account: Address,
+    ) {
+        weighted_threshold::install(env, &install_params, &context_rule, &smart_account);
+    }
+
+    fn uninstall(env: &Env, context_rule: ContextRule, smart_account: Address) {
+        weighted_threshold::uninstall(env, &context_rule, &smart_account);
+    }
+}
@@ -2,7 +2,8 @@
 
 # Stellar Soroban Remittance Deployment Script (Mainnet)
 # Builds the remittance smart-account contract, deploys four labeled
-# instances (A-D), and initializes them with the OpenZeppelin policy.
+# instances (A-D), initialized at deployment through their constructors
+# with the OpenZeppelin policy.
 
 set -euo pipefail
 
@@ -25,6 +26,9 @@ SOROSWAP_CONTRACT_ID="CAG5LRYQ5JVEUI5TEID72EYOVX44TTUJT5BQR2J6J77FH65PCCFAJDDH"
 NETWORK_PASSPHRASE="Public Global Stellar Network ; September 2015"
 FOREX_USDC_ACCOUNT_LABEL="${FOREX_USDC_ACCOUNT_LABEL:-A}"
 FOREX_EURC_ACCOUNT_LABEL="${FOREX_EURC_ACCOUNT_LABEL:-B}"
+FOREX_ORACLE_ID="${FOREX_ORACLE_ID:-}"
+FOREX_MAX_DEVIATION_BPS="${FOREX_MAX_DEVIATION_BPS:-100}"
+FOREX_ORACLE_MAX_AGE="${FOREX_ORACLE_MAX_AGE:-600}"
 MULTISIG_THRESHOLD="${MULTISIG_THRESHOLD:-3}"
 MULTISIG_LABEL="${MULTISIG_LABEL:-Global Treasury}"
 
@@ -95,11 +99,19 @@ echo -e "${GREEN}✓ Build ready (${WASM_PATH})${NC}"
 echo -e "${YELLOW}Step 4: Deploying contract instances${NC}"
 for label in "${LABELS[@]}"; do
   echo "Deploying smart account $label..."
+  # Constructor arguments are applied in the deploy transaction itself, so
+  # the account is never left uninitialized. Destinations are set in step 6
+  # once every address is known.
   deploy_output=$(stellar contract deploy \
     --wasm "$WASM_PATH" \
     --source-account "$SOURCE_ACCOUNT" \
     --network "$NETWORK" \
-    --network-passphrase "$NETWORK_PASSPHRASE"
+    --network-passphrase "$NETWORK_PASSPHRASE" \
+    -- \
+    --admin "$ADMIN_PUBLIC_KEY" \
+    --token "$USDC_CONTRACT_ID" \
+    --destinations "[]" \
+    --label "$label"
   )
   contract_id=$(echo "$deploy_output" | awk '/Contract Id/ {print $NF}' | tail -n1)
   if [[ -z "$contract_id" ]]; then
@@ -114,47 +126,29 @@ for label in "${LABELS[@]}"; do
   echo -e "  ${GREEN}Contract ID:${NC} $contract_id"
 done
 
-echo -e "${YELLOW}Step 5: Initializing accounts${NC}"
-for label in "${LABELS[@]}"; do
-  contract_id="${CONTRACT_IDS[$label]}"
-  echo "Initializing $label ($contract_id)..."
-  destinations=()
-  for other in "${LABELS[@]}"; do
-    if [[ "$other" != "$label" ]]; then
-      destinations+=("${CONTRACT_IDS[$other]}")
-    fi
-  done
-  destinations_json="["
-  for idx in "${!destinations[@]}"; do
-    if (( idx > 0 )); then
-      destinations_json+=","
-    fi
-    destinations_json+="\"${destinations[$idx]}\""
-  done
-  destinations_json+="]"
-  invoke_cmd=(
-    stellar contract invoke
-    --id "$contract_id"
-    --source-account "$SOURCE_ACCOUNT"
-    --sign-with-key "$ADMIN_SIGNER"
-    --network "$NETWORK"
-    --network-passphrase "$NETWORK_PASSPHRASE"
-    -- init
-    --admin "$ADMIN_PUBLIC_KEY"
-    --token "$USDC_CONTRACT_ID"
-    --destinations "$destinations_json"
-  )
-  invoke_cmd+=(--label "$label")
-  "${invoke_cmd[@]}"
+echo -e "${YELLOW}Step 5: Deploying multisig treasury${NC}"
+signers_json="["
+for idx in "${!LABELS[@]}"; do
+  label=${LABELS[$idx]}
+  if [[ $idx -gt 0 ]]; then
+    signers_json+=","
+  fi
+  signers_json+="\"${CONTRACT_IDS[$label]}\""
 done
+signers_json+="]"
 
-echo -e "${YELLOW}Step 6: Deploying multisig treasury${NC}"
 echo "Deploying multisig wallet..."
 multisig_output=$(stellar contract deploy \
   --wasm "$MULTISIG_WASM_PATH" \
   --source-account "$SOURCE_ACCOUNT" \
   --network "$NETWORK" \
-  --network-passphrase "$NETWORK_PASSPHRASE"
+  --network-passphrase "$NETWORK_PASSPHRASE" \
+  -- \
+  --admin "$ADMIN_PUBLIC_KEY" \
+  --token "$USDC_CONTRACT_ID" \
+  --signers "$signers_json" \
+  --threshold "$MULTISIG_THRESHOLD" \
+  --label "$MULTISIG_LABEL"
 )
 MULTISIG_CONTRACT_ID=$(echo "$multisig_output" | awk '/Contract Id/ {print $NF}' | tail -n1)
 if [[ -z "$MULTISIG_CONTRACT_ID" ]]; then
@@ -167,31 +161,8 @@ if [[ -z "$MULTISIG_CONTRACT_ID" ]]; then
 fi
 echo -e "  ${GREEN}Multisig Contract ID:${NC} $MULTISIG_CONTRACT_ID"
 
-signers_json="["
-for idx in "${!LABELS[@]}"; do
-  label=${LABELS[$idx]}
-  if [[ $idx -gt 0 ]]; then
-    signers_json+=","
-  fi
-  signers_json+="\"${CONTRACT_IDS[$label]}\""
-done
-signers_json+="]"
-
-echo "Initializing multisig wallet..."
-stellar contract invoke \
-  --id "$MULTISIG_CONTRACT_ID" \
-  --source-account "$SOURCE_ACCOUNT" \
-  --sign-with-key "$ADMIN_SIGNER" \
-  --network "$NETWORK" \
-  --network-passphrase "$NETWORK_PASSPHRASE" \
-  -- init \
-  --admin "$ADMIN_PUBLIC_KEY" \
-  --token "$USDC_CONTRACT_ID" \
-  --signers "$signers_json" \
-  --threshold "$MULTISIG_THRESHOLD" \
-  --label "$MULTISIG_LABEL"
 
-echo -e "${YELLOW}Step 7: Updating destinations with multisig${NC}"
+echo -e "${YELLOW}Step 6: Setting destinations (corridor wallets + multisig)${NC}"
 for label in "${LABELS[@]}"; do
   contract_id="${CONTRACT_IDS[$label]}"
   destinations=()
@@ -220,7 +191,7 @@ for label in "${LABELS[@]}"; do
     --destinations "$destinations_json"
 done
 
-echo -e "${YELLOW}Step 8: Configuring forex routing${NC}"
+echo -e "${YELLOW}Step 7: Configuring forex routing${NC}"
 
 if [[ "$FOREX_USDC_ACCOUNT_LABEL" == "$FOREX_EURC_ACCOUNT_LABEL" ]]; then
   echo -e "${RED}FOREX_USDC_ACCOUNT_LABEL and FOREX_EURC_ACCOUNT_LABEL must be different${NC}"
@@ -236,6 +207,11 @@ configure_forex_account() {
     exit 1
   fi
 
+  local oracle_args=()
+  if [[ -n "$FOREX_ORACLE_ID" ]]; then
+    oracle_args=(--oracle "{\"address\":\"$FOREX_ORACLE_ID\",\"max_deviation_bps\":$FOREX_MAX_DEVIATION_BPS,\"max_age\":$FOREX_ORACLE_MAX_AGE}")
+  fi
+
   echo "Configuring forex for account $label ($contract_id)..."
   stellar contract invoke \
     --id "$contract_id" \
@@ -245,13 +221,14 @@ configure_forex_account() {
     --network-passphrase "$NETWORK_PASSPHRASE" \
     -- configure_forex \
     --router "$SOROSWAP_CONTRACT_ID" \
-    --counter_token "$counter_token"
+    --counter_token "$counter_token" \
+    ${oracle_args[@]+"${oracle_args[@]}"}
 }
 
 configure_forex_account "$FOREX_USDC_ACCOUNT_LABEL" "$EURC_CONTRACT_ID"
 configure_forex_account "$FOREX_EURC_ACCOUNT_LABEL" "$USDC_CONTRACT_ID"
 
-echo -e "${YELLOW}Step 9: Writing config to $OUTPUT_CONFIG${NC}"
+echo -e "${YELLOW}Step 8: Writing config to $OUTPUT_CONFIG${NC}"
 mkdir -p "$CONFIG_DIR"
 cat > "$OUTPUT_CONFIG" <<EOF
 {
@@ -77,7 +77,7 @@ export function MultisigModal({ open, state, onClose, refresh }: Props) {
   }, [initiator, destination, localState.signers]);
 
   const pendingRequests = useMemo(
-    () => localState.requests.filter((req) => !req.executed),
+    () => localState.requests.filter((req) => req.status === "pending"),
     [localState.requests]
   );
 
@@ -33,14 +33,24 @@ export type BalancesResponse = ApiResponse<{
   balances: Record<AccountLabel, string>;
 }>;
 
+export type MultisigRequestStatus =
+  | "pending"
+  | "executed"
+  | "cancelled"
+  | "expired"
+  | "rejected";
+
 export type MultisigRequest = {
   id: number;
+  token: string;
   to: AccountLabel;
   amount: string;
   approvals: AccountLabel[];
   executed: boolean;
+  status: MultisigRequestStatus;
   initiator: AccountLabel;
   createdAt: number;
+  expiresAt: number;
   completedAt?: number;
 };
 